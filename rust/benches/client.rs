@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+use std::future::Future;
+use chrono::{TimeZone, Utc};
+use tonic::{Request, transport::Channel};
+use uuid::Uuid;
+use rmcs_api_client::Resource;
+use rmcs_api_client::resource::{DataType::*, DataValue::*, ArrayDataValue};
+use rmcs_api_server::utility::interceptor::TokenInterceptor;
+use rmcs_api_server::utility::test::{TestServerKind, TestServer};
+use rmcs_resource_api::data::DataSchema;
+use rmcs_resource_api::data::data_service_client::DataServiceClient;
+use rmcs_resource_api::buffer::BufferSelector;
+use rmcs_resource_api::buffer::buffer_service_client::BufferServiceClient;
+
+const ITERATION: u32 = 2000;
+
+async fn bench<F, Fut>(name: &str, f: F)
+    where F: Fn(u32) -> Fut, Fut: Future<Output = ()>
+{
+    // warm up the channel connection before measuring
+    for i in 0..10 {
+        f(i).await;
+    }
+    let start = Instant::now();
+    for i in 10..ITERATION + 10 {
+        f(i).await;
+    }
+    let elapsed = start.elapsed();
+    let rate = ITERATION as f64 / elapsed.as_secs_f64();
+    println!("{:<32} {:>10.2?} total {:>10.2?}/call {:>10.0} call/s", name, elapsed, elapsed / ITERATION, rate);
+}
+
+// client creation per call as done before generated clients were stored in resource
+async fn create_data_rebuild(channel: &Channel, token: &str, device_id: Uuid, model_id: Uuid, timestamp: i64) {
+    let interceptor = TokenInterceptor(token.to_owned());
+    let mut client =
+        DataServiceClient::with_interceptor(channel.to_owned(), interceptor);
+    let data = ArrayDataValue::from_vec(&[I32(1), I32(2)]);
+    let request = Request::new(DataSchema {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        timestamp,
+        data_bytes: data.to_bytes(),
+        data_type: data.get_types().into_iter().map(|el| el.into()).collect(),
+        tag: 0
+    });
+    client.create_data(request).await.unwrap();
+}
+
+async fn read_buffer_first_rebuild(channel: &Channel, token: &str, device_id: Uuid, model_id: Uuid) {
+    let interceptor = TokenInterceptor(token.to_owned());
+    let mut client =
+        BufferServiceClient::with_interceptor(channel.to_owned(), interceptor);
+    let request = Request::new(BufferSelector {
+        device_id: Some(device_id.as_bytes().to_vec()),
+        model_id: Some(model_id.as_bytes().to_vec()),
+        tag: None
+    });
+    client.read_buffer_first(request).await.unwrap();
+}
+
+#[tokio::main]
+async fn main()
+{
+    // start resource server
+    let resource_server = TestServer::new(TestServerKind::Resource);
+    resource_server.truncate_tables().await.unwrap();
+    resource_server.start_server();
+
+    let channel = Channel::from_shared(resource_server.address.clone()).unwrap().connect().await.unwrap();
+    let resource = Resource::new_with_channel(channel.clone());
+    let token = resource.token().0;
+
+    // create model, device and buffer used by the benchmark
+    let model_id = resource.create_model(Uuid::new_v4(), &[I32T, I32T], "UPLINK", "benchmark", None).await.unwrap();
+    let type_id = resource.create_type(Uuid::new_v4(), "Benchmark", None).await.unwrap();
+    resource.add_type_model(type_id, model_id).await.unwrap();
+    let device_id = Uuid::new_v4();
+    resource.create_device(device_id, device_id, type_id, "BENCH01", "Benchmark device", None).await.unwrap();
+    let timestamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    resource.create_buffer(device_id, model_id, timestamp, &[I32(1), I32(2)], None).await.unwrap();
+
+    let offset = |i: u32| timestamp + Duration::from_millis(i as u64);
+    bench("create_data (rebuild client)", |i| create_data_rebuild(&channel, &token, device_id, model_id, offset(i).timestamp_micros())).await;
+    // shift timestamp so stored client run does not collide with data of the previous run
+    let resource = &resource;
+    bench("create_data (stored client)", |i| async move {
+        resource.create_data(device_id, model_id, offset(i + 2 * ITERATION), &[I32(1), I32(2)], None).await.unwrap();
+    }).await;
+    bench("read_buffer_first (rebuild client)", |_| read_buffer_first_rebuild(&channel, &token, device_id, model_id)).await;
+    bench("read_buffer_first (stored client)", |_| async {
+        resource.read_buffer_first(Some(device_id), Some(model_id), None).await.unwrap();
+    }).await;
+
+    resource_server.stop_server();
+}
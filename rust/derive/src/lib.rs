@@ -0,0 +1,159 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments, Type};
+
+enum FieldKind {
+    Single,
+    Optional,
+    Multiple
+}
+
+// get inner type of Option<T> or Vec<T>, Vec<u8> is treated as a single bytes value
+fn field_kind(ty: &Type) -> (FieldKind, Type) {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner)) = args.args.first()
+    {
+        let is_u8 = matches!(inner, Type::Path(p) if p.path.is_ident("u8"));
+        if segment.ident == "Option" {
+            return (FieldKind::Optional, inner.clone());
+        }
+        if segment.ident == "Vec" && !is_u8 {
+            return (FieldKind::Multiple, inner.clone());
+        }
+    }
+    (FieldKind::Single, ty.clone())
+}
+
+fn profile_name(field: &syn::Field) -> syn::Result<String> {
+    let mut name = field.ident.as_ref().map(|i| i.to_string()).unwrap_or_default();
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("profile")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else {
+                Err(meta.error("unsupported profile attribute"))
+            }
+        })?;
+    }
+    Ok(name)
+}
+
+// crate path is set by #[profile(crate = "path")] on the struct when the client crate is renamed or re-exported
+fn crate_path(input: &DeriveInput) -> syn::Result<syn::Path> {
+    let mut path = syn::parse_quote!(::rmcs_api_client);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("profile")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported profile attribute"))
+            }
+        })?;
+    }
+    Ok(path)
+}
+
+#[proc_macro_derive(UserProfile, attributes(profile))]
+pub fn derive_user_profile(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match user_profile(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+fn user_profile(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(ident, "UserProfile requires a struct with named fields"))
+        },
+        _ => return Err(syn::Error::new_spanned(ident, "UserProfile can only be derived for a struct"))
+    };
+
+    let krate = crate_path(&input)?;
+    let mapping = quote!(#krate::auth::mapping);
+    let mode = quote!(#krate::auth::ProfileMode);
+    // generated locals use mixed site span so they can not clash with field names of the struct
+    let errors = Ident::new("errors", Span::mixed_site());
+    let profiles = Ident::new("profiles", Span::mixed_site());
+    let values = Ident::new("values", Span::mixed_site());
+    let mut profile_fields = Vec::new();
+    let mut to_profiles = Vec::new();
+    let mut reads = Vec::new();
+    let mut inits = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().unwrap();
+        let local = format_ident!("field_{}", field_ident, span = Span::mixed_site());
+        let name = profile_name(field)?;
+        let (kind, ty) = field_kind(&field.ty);
+        let (field_mode, read, field_values) = match kind {
+            FieldKind::Single => (
+                quote!(#mode::SingleRequired),
+                quote!(#mapping::read_single::<#ty>(#profiles, #name)),
+                quote!(::std::iter::once(self.#field_ident.clone()))
+            ),
+            FieldKind::Optional => (
+                quote!(#mode::SingleOptional),
+                quote!(#mapping::read_optional::<#ty>(#profiles, #name)),
+                quote!(self.#field_ident.iter().cloned())
+            ),
+            FieldKind::Multiple => (
+                quote!(#mode::MultipleOptional),
+                quote!(#mapping::read_multiple::<#ty>(#profiles, #name)),
+                quote!(self.#field_ident.iter().cloned())
+            )
+        };
+        profile_fields.push(quote!(#mapping::field::<#ty>(#name, #field_mode)));
+        to_profiles.push(quote!(#values.extend(#field_values.map(|v| (#name, v.into())));));
+        reads.push(quote!(let #local = #read.map_err(|e| #errors.push(e)).ok();));
+        inits.push(quote!(#field_ident: #local.unwrap()));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #mapping::UserProfile for #ident #ty_generics #where_clause {
+            fn profile_fields() -> ::std::vec::Vec<#mapping::ProfileField> {
+                ::std::vec![#(#profile_fields),*]
+            }
+            fn to_profiles(&self) -> ::std::vec::Vec<(&'static str, #krate::auth::DataValue)> {
+                let mut #values = ::std::vec::Vec::new();
+                #(#to_profiles)*
+                #values
+            }
+            fn from_profiles(#profiles: &[#krate::auth::UserProfileSchema])
+                -> ::std::result::Result<Self, #krate::Error>
+            {
+                let mut #errors = ::std::vec::Vec::new();
+                #(#reads)*
+                if !#errors.is_empty() {
+                    return ::std::result::Result::Err(#krate::Error::Profile(#errors));
+                }
+                ::std::result::Result::Ok(Self { #(#inits),* })
+            }
+        }
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub async fn load_from(auth: &#krate::Auth, user_id: #mapping::Uuid)
+                -> ::std::result::Result<Self, #krate::Error>
+            {
+                #mapping::load_profile::<Self>(auth, user_id).await
+            }
+            pub async fn save_to(&self, auth: &#krate::Auth, user_id: #mapping::Uuid)
+                -> ::std::result::Result<(), #krate::Error>
+            {
+                #mapping::save_profile(self, auth, user_id).await
+            }
+            pub async fn create_role_profiles(auth: &#krate::Auth, role_id: #mapping::Uuid)
+                -> ::std::result::Result<::std::vec::Vec<i32>, #krate::Error>
+            {
+                #mapping::create_role_profiles::<Self>(auth, role_id).await
+            }
+        }
+    })
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::fmt;
+use tonic::Status;
+use uuid::Uuid;
+use crate::auth::{Auth, RoleSchema};
+
+#[derive(Debug, Clone)]
+struct ApiAccess {
+    id: Uuid,
+    name: String,
+    procedures: Vec<(Uuid, String)>,
+    roles: Vec<RoleSchema>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessRow {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub roles: Vec<String>,
+    pub allowed: Vec<bool>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessMatrix {
+    pub api_id: Uuid,
+    pub api_name: String,
+    pub procedures: Vec<String>,
+    pub rows: Vec<AccessRow>
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessResolver {
+    auth: Auth,
+    apis: HashMap<String, ApiAccess>,
+    user_roles: HashMap<Uuid, Vec<RoleSchema>>
+}
+
+impl AccessResolver {
+
+    pub fn new(auth: &Auth) -> Self {
+        AccessResolver {
+            auth: auth.clone(),
+            apis: HashMap::new(),
+            user_roles: HashMap::new()
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.apis.clear();
+        self.user_roles.clear();
+    }
+
+    async fn api(&mut self, api_name: &str) -> Result<&ApiAccess, Status> {
+        if !self.apis.contains_key(api_name) {
+            let api = self.auth.read_api_by_name(api_name).await?;
+            let roles = self.auth.list_role_by_api(api.id).await?;
+            let access = ApiAccess {
+                id: api.id,
+                name: api.name,
+                procedures: api.procedures.into_iter().map(|p| (p.id, p.name)).collect(),
+                roles
+            };
+            self.apis.insert(api_name.to_owned(), access);
+        }
+        Ok(&self.apis[api_name])
+    }
+
+    async fn roles(&mut self, user_id: Uuid) -> Result<&[RoleSchema], Status> {
+        if !self.user_roles.contains_key(&user_id) {
+            let roles = self.auth.list_role_by_user(user_id).await?;
+            self.user_roles.insert(user_id, roles);
+        }
+        Ok(&self.user_roles[&user_id])
+    }
+
+    pub async fn can(&mut self, user_id: Uuid, api_name: &str, procedure_name: &str) -> Result<bool, Status> {
+        let api = self.api(api_name).await?;
+        let api_id = api.id;
+        let procedure_id = match api.procedures.iter().find(|(_, name)| name == procedure_name) {
+            Some((id, _)) => *id,
+            None => return Ok(false)
+        };
+        let roles = self.roles(user_id).await?;
+        Ok(roles.iter().any(|r| r.api_id == api_id && r.procedures.contains(&procedure_id)))
+    }
+
+    pub async fn matrix(&mut self, api_name: &str) -> Result<AccessMatrix, Status> {
+        let api = self.api(api_name).await?.clone();
+        let users = self.auth.list_user_by_api(api.id).await?;
+        let mut rows = Vec::new();
+        for user in users {
+            let roles: Vec<&RoleSchema> = user.roles.iter()
+                .filter(|r| r.api_id == api.id)
+                .filter_map(|r| api.roles.iter().find(|role| role.name == r.role))
+                .collect();
+            let allowed = api.procedures.iter()
+                .map(|(id, _)| roles.iter().any(|r| r.procedures.contains(id)))
+                .collect();
+            rows.push(AccessRow {
+                user_id: user.id,
+                user_name: user.name,
+                roles: roles.iter().map(|r| r.name.clone()).collect(),
+                allowed
+            });
+        }
+        Ok(AccessMatrix {
+            api_id: api.id,
+            api_name: api.name,
+            procedures: api.procedures.into_iter().map(|(_, name)| name).collect(),
+            rows
+        })
+    }
+
+}
+
+impl fmt::Display for AccessMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.rows.iter().map(|r| r.user_name.len()).max().unwrap_or(0).max(4);
+        write!(f, "{:width$}", "user")?;
+        for procedure in &self.procedures {
+            write!(f, " | {}", procedure)?;
+        }
+        writeln!(f)?;
+        for row in &self.rows {
+            write!(f, "{:width$}", row.user_name)?;
+            for (procedure, allowed) in self.procedures.iter().zip(&row.allowed) {
+                let mark = if *allowed { "x" } else { "-" };
+                write!(f, " | {:^w$}", mark, w = procedure.len())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
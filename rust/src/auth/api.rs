@@ -1,12 +1,10 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_auth_api::api::api_service_client::ApiServiceClient;
 use rmcs_auth_api::api::{
     ApiId, ApiIds, ApiName, ApiCategory, ApiOption, ApiSchema, ApiUpdate, 
     ProcedureId, ProcedureIds, ProcedureName, ProcedureOption, ProcedureSchema, ProcedureUpdate
 };
 use crate::auth::Auth;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const API_NOT_FOUND: &str = "requested api not found";
 const PROC_NOT_FOUND: &str = "requested procedure not found";
@@ -14,9 +12,7 @@ const PROC_NOT_FOUND: &str = "requested procedure not found";
 pub(crate) async fn read_api(auth: &Auth, id: Uuid)
     -> Result<ApiSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiId {
         id: id.as_bytes().to_vec()
     });
@@ -27,9 +23,7 @@ pub(crate) async fn read_api(auth: &Auth, id: Uuid)
 pub(crate) async fn read_api_by_name(auth: &Auth, name: &str)
     -> Result<ApiSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiName {
         name: name.to_owned()
     });
@@ -42,9 +36,7 @@ pub(crate) async fn read_api_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_api_by_ids(auth: &Auth, ids: &[Uuid])
     -> Result<Vec<ApiSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -57,9 +49,7 @@ pub(crate) async fn list_api_by_ids(auth: &Auth, ids: &[Uuid])
 pub(crate) async fn list_api_by_name(auth: &Auth, name: &str)
     -> Result<Vec<ApiSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiName {
         name: name.to_owned()
     });
@@ -72,9 +62,7 @@ pub(crate) async fn list_api_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_api_by_category(auth: &Auth, category: &str)
     -> Result<Vec<ApiSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiCategory {
         category: category.to_owned()
     });
@@ -87,9 +75,7 @@ pub(crate) async fn list_api_by_category(auth: &Auth, category: &str)
 pub(crate) async fn list_api_option(auth: &Auth, name: Option<&str>, category: Option<&str>)
     -> Result<Vec<ApiSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiOption {
         name: name.map(|s| s.to_owned()),
         category: category.map(|s| s.to_owned())
@@ -103,9 +89,7 @@ pub(crate) async fn list_api_option(auth: &Auth, name: Option<&str>, category: O
 pub(crate) async fn create_api(auth: &Auth, id: Uuid, name: &str, address: &str, category: &str, description: &str, password: &str, access_key: &[u8])
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -125,9 +109,7 @@ pub(crate) async fn create_api(auth: &Auth, id: Uuid, name: &str, address: &str,
 pub(crate) async fn update_api(auth: &Auth, id: Uuid, name: Option<&str>, address: Option<&str>, category: Option<&str>, description: Option<&str>, password: Option<&str>, access_key: Option<&[u8]>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -145,9 +127,7 @@ pub(crate) async fn update_api(auth: &Auth, id: Uuid, name: Option<&str>, addres
 pub(crate) async fn delete_api(auth: &Auth, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiId {
         id: id.as_bytes().to_vec()
     });
@@ -159,9 +139,7 @@ pub(crate) async fn delete_api(auth: &Auth, id: Uuid)
 pub(crate) async fn read_procedure(auth: &Auth, id: Uuid)
     -> Result<ProcedureSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureId {
         id: id.as_bytes().to_vec()
     });
@@ -174,9 +152,7 @@ pub(crate) async fn read_procedure(auth: &Auth, id: Uuid)
 pub(crate) async fn read_procedure_by_name(auth: &Auth, api_id: Uuid, name: &str)
     -> Result<ProcedureSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureName {
         api_id: api_id.as_bytes().to_vec(),
         name: name.to_owned()
@@ -190,9 +166,7 @@ pub(crate) async fn read_procedure_by_name(auth: &Auth, api_id: Uuid, name: &str
 pub(crate) async fn list_procedure_by_ids(auth: &Auth, ids: &[Uuid])
     -> Result<Vec<ProcedureSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -205,9 +179,7 @@ pub(crate) async fn list_procedure_by_ids(auth: &Auth, ids: &[Uuid])
 pub(crate) async fn list_procedure_by_api(auth: &Auth, api_id: Uuid)
     -> Result<Vec<ProcedureSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ApiId {
         id: api_id.as_bytes().to_vec()
     });
@@ -220,9 +192,7 @@ pub(crate) async fn list_procedure_by_api(auth: &Auth, api_id: Uuid)
 pub(crate) async fn list_procedure_by_name(auth: &Auth, name: &str)
     -> Result<Vec<ProcedureSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureName {
         api_id: Uuid::nil().as_bytes().to_vec(),
         name: name.to_owned()
@@ -236,9 +206,7 @@ pub(crate) async fn list_procedure_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_procedure_option(auth: &Auth, api_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<ProcedureSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureOption {
         api_id: api_id.map(|id| id.as_bytes().to_vec()),
         name: name.map(|s| s.to_owned())
@@ -252,9 +220,7 @@ pub(crate) async fn list_procedure_option(auth: &Auth, api_id: Option<Uuid>, nam
 pub(crate) async fn create_procedure(auth: &Auth, id: Uuid, api_id: Uuid, name: &str, description: &str)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureSchema {
         id: id.as_bytes().to_vec(),
         api_id: api_id.as_bytes().to_vec(),
@@ -271,9 +237,7 @@ pub(crate) async fn create_procedure(auth: &Auth, id: Uuid, api_id: Uuid, name:
 pub(crate) async fn update_procedure(auth: &Auth, id: Uuid, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -287,9 +251,7 @@ pub(crate) async fn update_procedure(auth: &Auth, id: Uuid, name: Option<&str>,
 pub(crate) async fn delete_procedure(auth: &Auth, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ApiServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.api.clone();
     let request = Request::new(ProcedureId {
         id: id.as_bytes().to_vec()
     });
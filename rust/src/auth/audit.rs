@@ -0,0 +1,151 @@
+use std::net::IpAddr;
+use chrono::{Duration, Utc};
+use tonic::Status;
+use uuid::Uuid;
+use crate::auth::{Auth, TokenSchema, TokenIp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStatus {
+    Active,
+    ExpiringSoon,
+    Expired,
+    Orphaned
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuditOptions {
+    pub expire_within: Duration,
+    pub ip: Option<IpAddr>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenAudit {
+    pub token: TokenSchema,
+    pub status: TokenStatus,
+    pub ip_mismatch: bool
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthTokenGroup {
+    pub auth_token: String,
+    pub user_id: Uuid,
+    pub tokens: Vec<TokenAudit>
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenAuditReport {
+    pub groups: Vec<AuthTokenGroup>
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RevokeReport {
+    pub auth_tokens: Vec<String>,
+    pub access_ids: Vec<i32>
+}
+
+impl TokenAuditReport {
+
+    pub fn tokens(&self) -> impl Iterator<Item = &TokenAudit> {
+        self.groups.iter().flat_map(|g| g.tokens.iter())
+    }
+
+    pub fn by_status(&self, status: TokenStatus) -> Vec<&TokenAudit> {
+        self.tokens().filter(|t| t.status == status).collect()
+    }
+
+    pub fn ip_mismatched(&self) -> Vec<&TokenAudit> {
+        self.tokens().filter(|t| t.ip_mismatch).collect()
+    }
+
+    pub fn count(&self, status: TokenStatus) -> usize {
+        self.tokens().filter(|t| t.status == status).count()
+    }
+
+}
+
+pub fn classify_token(token: &TokenSchema, orphaned: bool, options: &AuditOptions) -> TokenAudit {
+    let now = Utc::now();
+    let status = if orphaned {
+        TokenStatus::Orphaned
+    } else if token.expire <= now {
+        TokenStatus::Expired
+    } else if token.expire - now <= options.expire_within {
+        TokenStatus::ExpiringSoon
+    } else {
+        TokenStatus::Active
+    };
+    // token without ip is not locked to any ip address
+    let ip_mismatch = match &options.ip {
+        Some(ip) => token.ip_addr().is_some() && !token.ip_matches(*ip),
+        None => false
+    };
+    TokenAudit { token: token.clone(), status, ip_mismatch }
+}
+
+fn group_tokens(report: &mut TokenAuditReport, tokens: Vec<TokenAudit>) {
+    for audit in tokens {
+        match report.groups.iter_mut().find(|g| g.auth_token == audit.token.auth_token) {
+            Some(group) => group.tokens.push(audit),
+            None => report.groups.push(AuthTokenGroup {
+                auth_token: audit.token.auth_token.clone(),
+                user_id: audit.token.user_id,
+                tokens: vec![audit]
+            })
+        }
+    }
+}
+
+async fn audit_user(auth: &Auth, user_id: Uuid, options: &AuditOptions, report: &mut TokenAuditReport)
+    -> Result<(), Status>
+{
+    // token of user without any role can not be used anymore
+    let orphaned = auth.read_user(user_id).await?.roles.is_empty();
+    let tokens = auth.list_token_by_user(user_id).await?
+        .iter()
+        .map(|t| classify_token(t, orphaned, options))
+        .collect();
+    group_tokens(report, tokens);
+    Ok(())
+}
+
+pub(crate) async fn audit_user_token(auth: &Auth, user_id: Uuid, options: &AuditOptions)
+    -> Result<TokenAuditReport, Status>
+{
+    let mut report = TokenAuditReport::default();
+    audit_user(auth, user_id, options, &mut report).await?;
+    Ok(report)
+}
+
+pub(crate) async fn audit_token(auth: &Auth, options: &AuditOptions)
+    -> Result<TokenAuditReport, Status>
+{
+    let mut report = TokenAuditReport::default();
+    for user in auth.list_user_option(None, None, None).await? {
+        audit_user(auth, user.id, options, &mut report).await?;
+    }
+    Ok(report)
+}
+
+pub(crate) async fn revoke_token(auth: &Auth, report: &TokenAuditReport, statuses: &[TokenStatus])
+    -> Result<RevokeReport, Status>
+{
+    let mut revoked = RevokeReport::default();
+    for group in &report.groups {
+        let selected: Vec<&TokenAudit> = group.tokens.iter().filter(|t| statuses.contains(&t.status)).collect();
+        if selected.is_empty() {
+            continue;
+        }
+        // revoke the whole login session when all of its access tokens are selected
+        if selected.len() == group.tokens.len() && !group.auth_token.is_empty() {
+            auth.delete_auth_token(&group.auth_token).await?;
+            revoked.auth_tokens.push(group.auth_token.clone());
+            revoked.access_ids.extend(selected.iter().map(|t| t.token.access_id));
+        } else {
+            for audit in selected {
+                auth.delete_access_token(audit.token.access_id).await?;
+                revoked.access_ids.push(audit.token.access_id);
+            }
+        }
+    }
+    Ok(revoked)
+}
@@ -1,6 +1,5 @@
-use tonic::{Request, Status};
+use tonic::{Code, Request, Status};
 use uuid::Uuid;
-use rmcs_auth_api::auth::auth_service_client::AuthServiceClient;
 use rmcs_auth_api::auth::{
     UserKeyRequest, UserLoginRequest, UserLoginResponse,
     UserRefreshRequest, UserRefreshResponse,
@@ -15,15 +14,26 @@ const ENCRYPT_ERR: &str = "encrypt password error";
 pub(crate) async fn user_login(auth: &Auth, username: &str, password: &str)
     -> Result<UserLoginResponse, Status>
 {
-    let mut client = AuthServiceClient::new(auth.channel.to_owned());
-    let request = Request::new(UserKeyRequest {
-    });
-    // get transport public key of requested user and encrypt the password
-    let response = client.user_login_key(request).await?.into_inner();
-    let pub_key = import_public_key(response.public_key.as_slice())
-        .map_err(|_| Status::internal(KEY_IMPORT_ERR))?;
-    let passhash = encrypt_message(password.as_bytes(), pub_key)
-        .map_err(|_| Status::internal(ENCRYPT_ERR))?;
+    let (passhash, cached) = encrypt_password(auth, password).await?;
+    match login_request(auth, username, passhash).await {
+        // server may have changed its transport key so the password can not be decrypted,
+        // a new key is fetched and the login is sent again when the cached key was used
+        Err(status) if status.code() == Code::InvalidArgument => {
+            auth.transport_key.lock().await.take();
+            if !cached {
+                return Err(status);
+            }
+            let (passhash, _) = encrypt_password(auth, password).await?;
+            login_request(auth, username, passhash).await
+        },
+        result => result
+    }
+}
+
+async fn login_request(auth: &Auth, username: &str, passhash: Vec<u8>)
+    -> Result<UserLoginResponse, Status>
+{
+    let mut client = auth.clients.auth.clone();
     // request access and refresh tokens
     let request = Request::new(UserLoginRequest {
         username: username.to_owned(),
@@ -33,10 +43,37 @@ pub(crate) async fn user_login(auth: &Auth, username: &str, password: &str)
     Ok(response)
 }
 
+// transport public key of the server is fetched once and shared by all clones on the same channel,
+// return the encrypted password and whether the key was already cached
+async fn encrypt_password(auth: &Auth, password: &str)
+    -> Result<(Vec<u8>, bool), Status>
+{
+    let mut transport_key = auth.transport_key.lock().await;
+    let cached = transport_key.is_some();
+    let key = match transport_key.as_ref() {
+        Some(key) => key.clone(),
+        None => {
+            let mut client = auth.clients.auth.clone();
+            let request = Request::new(UserKeyRequest {
+            });
+            let response = client.user_login_key(request).await?.into_inner();
+            *transport_key = Some(response.public_key.clone());
+            response.public_key
+        }
+    };
+    let passhash = import_public_key(key.as_slice())
+        .map_err(|_| Status::internal(KEY_IMPORT_ERR))
+        .and_then(|pub_key| encrypt_message(password.as_bytes(), pub_key).map_err(|_| Status::internal(ENCRYPT_ERR)));
+    if passhash.is_err() {
+        *transport_key = None;
+    }
+    Ok((passhash?, cached))
+}
+
 pub(crate) async fn user_refresh(auth: &Auth, api_id: Uuid, access_token: &str, refresh_token: &str)
     -> Result<UserRefreshResponse, Status>
 {
-    let mut client = AuthServiceClient::new(auth.channel.to_owned());
+    let mut client = auth.clients.auth.clone();
     let request = Request::new(UserRefreshRequest {
         api_id: api_id.as_bytes().to_vec(),
         access_token: access_token.to_owned(),
@@ -49,7 +86,7 @@ pub(crate) async fn user_refresh(auth: &Auth, api_id: Uuid, access_token: &str,
 pub(crate) async fn user_logout(auth: &Auth, user_id: Uuid, auth_token: &str)
     -> Result<UserLogoutResponse, Status>
 {
-    let mut client = AuthServiceClient::new(auth.channel.to_owned());
+    let mut client = auth.clients.auth.clone();
     let request = Request::new(UserLogoutRequest {
         user_id: user_id.as_bytes().to_vec(),
         auth_token: auth_token.to_owned()
@@ -0,0 +1,124 @@
+use tonic::Status;
+use uuid::Uuid;
+use crate::auth::Auth;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CascadeReport {
+    pub dry_run: bool,
+    pub apis: Vec<Uuid>,
+    pub procedures: Vec<Uuid>,
+    pub roles: Vec<Uuid>,
+    pub users: Vec<Uuid>,
+    pub role_accesses: Vec<(Uuid, Uuid)>,
+    pub user_roles: Vec<(Uuid, Uuid)>,
+    pub role_profiles: Vec<i32>,
+    pub user_profiles: Vec<i32>,
+    pub tokens: Vec<i32>
+}
+
+// deletion is not transactional, so the report of what was already deleted is returned together with the error
+pub(crate) async fn delete_user_cascade(auth: &Auth, id: Uuid, dry_run: bool)
+    -> Result<CascadeReport, (Status, CascadeReport)>
+{
+    let mut report = CascadeReport { dry_run, ..Default::default() };
+    match user_cascade(auth, id, &mut report).await {
+        Ok(()) => Ok(report),
+        Err(status) => Err((status, report))
+    }
+}
+
+pub(crate) async fn delete_role_cascade(auth: &Auth, id: Uuid, dry_run: bool)
+    -> Result<CascadeReport, (Status, CascadeReport)>
+{
+    let mut report = CascadeReport { dry_run, ..Default::default() };
+    match role_cascade(auth, id, &mut report).await {
+        Ok(()) => Ok(report),
+        Err(status) => Err((status, report))
+    }
+}
+
+pub(crate) async fn delete_api_cascade(auth: &Auth, id: Uuid, dry_run: bool)
+    -> Result<CascadeReport, (Status, CascadeReport)>
+{
+    let mut report = CascadeReport { dry_run, ..Default::default() };
+    match api_cascade(auth, id, &mut report).await {
+        Ok(()) => Ok(report),
+        Err(status) => Err((status, report))
+    }
+}
+
+async fn api_cascade(auth: &Auth, id: Uuid, report: &mut CascadeReport)
+    -> Result<(), Status>
+{
+    // roles must be deleted first to unlink role accesses to api procedures
+    for role in auth.list_role_by_api(id).await? {
+        role_cascade(auth, role.id, report).await?;
+    }
+    for procedure in auth.list_procedure_by_api(id).await? {
+        if !report.dry_run {
+            auth.delete_procedure(procedure.id).await?;
+        }
+        report.procedures.push(procedure.id);
+    }
+    if !report.dry_run {
+        auth.delete_api(id).await?;
+    }
+    report.apis.push(id);
+    Ok(())
+}
+
+async fn user_cascade(auth: &Auth, id: Uuid, report: &mut CascadeReport)
+    -> Result<(), Status>
+{
+    let tokens = auth.list_token_by_user(id).await?;
+    if !report.dry_run && !tokens.is_empty() {
+        auth.delete_token_by_user(id).await?;
+    }
+    report.tokens.extend(tokens.into_iter().map(|t| t.access_id));
+    for profile in auth.list_user_profile_by_user(id).await? {
+        if !report.dry_run {
+            auth.delete_user_profile(profile.id).await?;
+        }
+        report.user_profiles.push(profile.id);
+    }
+    for role in auth.list_role_by_user(id).await? {
+        if !report.dry_run {
+            auth.remove_user_role(id, role.id).await?;
+        }
+        report.user_roles.push((id, role.id));
+    }
+    if !report.dry_run {
+        auth.delete_user(id).await?;
+    }
+    report.users.push(id);
+    Ok(())
+}
+
+async fn role_cascade(auth: &Auth, id: Uuid, report: &mut CascadeReport)
+    -> Result<(), Status>
+{
+    let role = auth.read_role(id).await?;
+    for user in auth.list_user_by_role(id).await? {
+        if !report.dry_run {
+            auth.remove_user_role(user.id, id).await?;
+        }
+        report.user_roles.push((user.id, id));
+    }
+    for procedure_id in role.procedures {
+        if !report.dry_run {
+            auth.remove_role_access(id, procedure_id).await?;
+        }
+        report.role_accesses.push((id, procedure_id));
+    }
+    for profile in auth.list_role_profile_by_role(id).await? {
+        if !report.dry_run {
+            auth.delete_role_profile(profile.id).await?;
+        }
+        report.role_profiles.push(profile.id);
+    }
+    if !report.dry_run {
+        auth.delete_role(id).await?;
+    }
+    report.roles.push(id);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use crate::auth::{RoleSchema, TokenSchema};
+
+pub fn ip_to_bytes(ip: Option<IpAddr>) -> Vec<u8> {
+    match ip {
+        Some(IpAddr::V4(ip)) => ip.octets().to_vec(),
+        Some(IpAddr::V6(ip)) => ip.octets().to_vec(),
+        None => Vec::new()
+    }
+}
+
+pub fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    if let Ok(octets) = <[u8; 4]>::try_from(bytes) {
+        Some(IpAddr::V4(Ipv4Addr::from(octets)))
+    } else if let Ok(octets) = <[u8; 16]>::try_from(bytes) {
+        Some(IpAddr::V6(Ipv6Addr::from(octets)))
+    } else {
+        None
+    }
+}
+
+// ip change of token update, token with cleared ip is not locked to any ip address
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IpUpdate {
+    #[default]
+    Keep,
+    Set(IpAddr),
+    Clear
+}
+
+impl IpUpdate {
+    pub(crate) fn to_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            IpUpdate::Keep => None,
+            IpUpdate::Set(ip) => Some(ip_to_bytes(Some(ip))),
+            IpUpdate::Clear => Some(Vec::new())
+        }
+    }
+}
+
+pub trait TokenIp {
+    fn ip_addr(&self) -> Option<IpAddr>;
+    fn ip_string(&self) -> String;
+    fn ip_matches(&self, ip: IpAddr) -> bool;
+}
+
+impl TokenIp for TokenSchema {
+
+    fn ip_addr(&self) -> Option<IpAddr> {
+        ip_from_bytes(&self.ip)
+    }
+
+    fn ip_string(&self) -> String {
+        self.ip_addr().map(|ip| ip.to_string()).unwrap_or_default()
+    }
+
+    // ipv4 address mapped to ipv6 is equal to the ipv4 address
+    fn ip_matches(&self, ip: IpAddr) -> bool {
+        self.ip_addr().map(|i| i.to_canonical()) == Some(ip.to_canonical())
+    }
+
+}
+
+// token without ip is not locked to any ip address even when the role has ip lock
+pub fn ip_lock_allowed(role: &RoleSchema, token: &TokenSchema, ip: IpAddr) -> bool {
+    !role.ip_lock || token.ip_addr().is_none() || token.ip_matches(ip)
+}
@@ -0,0 +1,102 @@
+pub use uuid::Uuid;
+use crate::auth::{Auth, UserProfileSchema, ProfileMode, ProfileError, DataType, DataValue};
+use crate::error::Error;
+
+pub trait ProfileType: Clone + Into<DataValue> + TryFrom<DataValue> {
+    const DATA_TYPE: DataType;
+}
+
+macro_rules! profile_type {
+    ($($ty:ty => $data_type:ident),*) => {
+        $(impl ProfileType for $ty {
+            const DATA_TYPE: DataType = DataType::$data_type;
+        })*
+    };
+}
+
+profile_type!(
+    i8 => I8T, i16 => I16T, i32 => I32T, i64 => I64T, i128 => I128T,
+    u8 => U8T, u16 => U16T, u32 => U32T, u64 => U64T, u128 => U128T,
+    f32 => F32T, f64 => F64T, bool => BoolT, char => CharT, String => StringT, Vec<u8> => BytesT
+);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileField {
+    pub name: &'static str,
+    pub value_type: DataType,
+    pub mode: ProfileMode
+}
+
+pub trait UserProfile: Sized {
+    fn profile_fields() -> Vec<ProfileField>;
+    fn to_profiles(&self) -> Vec<(&'static str, DataValue)>;
+    fn from_profiles(profiles: &[UserProfileSchema]) -> Result<Self, Error>;
+}
+
+pub fn field<T: ProfileType>(name: &'static str, mode: ProfileMode) -> ProfileField {
+    ProfileField { name, value_type: T::DATA_TYPE, mode }
+}
+
+fn read_values<T: ProfileType>(profiles: &[UserProfileSchema], name: &str) -> Result<Vec<T>, ProfileError> {
+    let mut named: Vec<&UserProfileSchema> = profiles.iter().filter(|p| p.name == name).collect();
+    named.sort_by_key(|p| p.order);
+    named.into_iter()
+        .map(|p| T::try_from(p.value.clone()).map_err(|_| ProfileError::TypeUnmatch {
+            name: name.to_owned(),
+            expected: T::DATA_TYPE,
+            found: p.value.get_type()
+        }))
+        .collect()
+}
+
+pub fn read_single<T: ProfileType>(profiles: &[UserProfileSchema], name: &str) -> Result<T, ProfileError> {
+    read_optional(profiles, name)?
+        .ok_or(ProfileError::Missing { name: name.to_owned() })
+}
+
+pub fn read_optional<T: ProfileType>(profiles: &[UserProfileSchema], name: &str) -> Result<Option<T>, ProfileError> {
+    let values = read_values(profiles, name)?;
+    if values.len() > 1 {
+        return Err(ProfileError::Multiple { name: name.to_owned() });
+    }
+    Ok(values.into_iter().next())
+}
+
+pub fn read_multiple<T: ProfileType>(profiles: &[UserProfileSchema], name: &str) -> Result<Vec<T>, ProfileError> {
+    read_values(profiles, name)
+}
+
+pub async fn load_profile<T: UserProfile>(auth: &Auth, user_id: Uuid) -> Result<T, Error> {
+    let profiles = auth.list_user_profile_by_user(user_id).await?;
+    T::from_profiles(&profiles)
+}
+
+pub async fn save_profile<T: UserProfile>(value: &T, auth: &Auth, user_id: Uuid) -> Result<(), Error> {
+    let profiles = auth.list_user_profile_by_user(user_id).await?;
+    let values = value.to_profiles();
+    for field in T::profile_fields() {
+        let mut stored: Vec<&UserProfileSchema> = profiles.iter().filter(|p| p.name == field.name).collect();
+        stored.sort_by_key(|p| p.order);
+        let new_values: Vec<&DataValue> = values.iter().filter(|(n, _)| *n == field.name).map(|(_, v)| v).collect();
+        // update stored values in order, then create the rest or delete the leftover
+        for (i, new_value) in new_values.iter().enumerate() {
+            match stored.get(i) {
+                Some(profile) if &profile.value == *new_value => {},
+                Some(profile) => auth.update_user_profile(profile.id, None, Some((*new_value).clone())).await?,
+                None => { auth.create_user_profile(user_id, field.name, (*new_value).clone()).await?; }
+            }
+        }
+        for profile in stored.iter().skip(new_values.len()) {
+            auth.delete_user_profile(profile.id).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn create_role_profiles<T: UserProfile>(auth: &Auth, role_id: Uuid) -> Result<Vec<i32>, Error> {
+    let mut ids = Vec::new();
+    for field in T::profile_fields() {
+        ids.push(auth.create_role_profile(role_id, field.name, field.value_type, field.mode).await?);
+    }
+    Ok(ids)
+}
@@ -4,12 +4,36 @@ pub mod user;
 pub mod profile;
 pub mod token;
 pub mod auth;
+pub mod cascade;
+pub mod validator;
+pub mod mapping;
+pub mod audit;
+pub mod ip;
+pub mod access;
+pub mod sync;
+pub mod rotate;
 pub mod utility {
     pub use rmcs_auth_db::utility::generate_access_key;
     pub use rmcs_auth_db::utility::generate_token_string;
 }
 
+use std::net::IpAddr;
+use std::future::Future;
+use std::time::Duration;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 use tonic::{Status, transport::Channel};
+use tonic::service::interceptor::InterceptedService;
+use rmcs_auth_api::api::api_service_client::ApiServiceClient;
+use rmcs_auth_api::role::role_service_client::RoleServiceClient;
+use rmcs_auth_api::user::user_service_client::UserServiceClient;
+use rmcs_auth_api::profile::profile_service_client::ProfileServiceClient;
+use rmcs_auth_api::token::token_service_client::TokenServiceClient;
+use rmcs_auth_api::auth::auth_service_client::AuthServiceClient;
+use crate::error::Error;
+use crate::settings::Settings;
+use crate::retry::RetryPolicy;
+use crate::options::{CallOptions, CallInterceptor, CallToken, DEFAULT_TIMEOUT, deadline, with_call_options};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 pub use rmcs_auth_db::schema::api::{ApiSchema, ProcedureSchema};
@@ -17,13 +41,56 @@ pub use rmcs_auth_db::schema::auth_role::RoleSchema;
 pub use rmcs_auth_db::schema::auth_user::{UserSchema, UserRoleSchema};
 pub use rmcs_auth_db::schema::auth_token::TokenSchema;
 pub use rmcs_auth_db::schema::profile::{RoleProfileSchema, UserProfileSchema, ProfileMode};
+pub use cascade::CascadeReport;
+pub use validator::ProfileError;
+pub use ip::{TokenIp, IpUpdate};
+pub use access::{AccessResolver, AccessMatrix};
+pub use sync::{SyncOptions, SyncReport};
+pub use rotate::{KeyRotation, RoleKeyRotation, TokenRevoke};
+pub use audit::{TokenStatus, AuditOptions, TokenAuditReport, RevokeReport};
+pub use mapping::UserProfile;
+pub use rmcs_api_client_derive::UserProfile;
 use rmcs_auth_api::auth::{UserLoginResponse, UserRefreshResponse, UserLogoutResponse};
 pub use rmcs_resource_db::schema::value::{DataValue, DataType, ArrayDataValue};
 
+const PLAINTEXT_PASSWORD: &str = "password can not be sent as plain text over non TLS channel";
+
 #[derive(Debug, Clone)]
 pub struct Auth {
     channel: Channel,
-    auth_token: String
+    auth_token: String,
+    transport_key: Arc<Mutex<Option<Vec<u8>>>>,
+    plaintext_password: bool,
+    options: Arc<CallOptions>,
+    clients: Arc<AuthClients>,
+    retry: RetryPolicy,
+    timeout: Option<Duration>
+}
+
+type AuthService = InterceptedService<Channel, CallInterceptor>;
+
+// generated clients are created once and cloned cheaply on every call
+#[derive(Debug, Clone)]
+struct AuthClients {
+    api: ApiServiceClient<AuthService>,
+    role: RoleServiceClient<AuthService>,
+    user: UserServiceClient<AuthService>,
+    profile: ProfileServiceClient<AuthService>,
+    token: TokenServiceClient<AuthService>,
+    auth: AuthServiceClient<Channel>
+}
+
+impl AuthClients {
+    fn new(channel: &Channel, interceptor: CallInterceptor) -> Self {
+        AuthClients {
+            api: ApiServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            role: RoleServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            user: UserServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            profile: ProfileServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            token: TokenServiceClient::with_interceptor(channel.to_owned(), interceptor),
+            auth: AuthServiceClient::new(channel.to_owned())
+        }
+    }
 }
 
 impl Auth {
@@ -34,46 +101,120 @@ impl Auth {
             .connect()
             .await
             .expect(&format!("Error making channel to {}", addr));
-        Auth {
-            channel,
-            auth_token: String::new()
-        }
+        Self::new_with_channel(channel)
+    }
+
+    pub async fn try_new(addr: &str) -> Result<Auth, Error> {
+        let channel = Channel::from_shared(addr.to_owned())
+            .map_err(|_| Error::InvalidAddress(addr.to_owned()))?
+            .connect()
+            .await?;
+        Ok(Self::new_with_channel(channel))
+    }
+
+    pub async fn from_env() -> Result<Auth, Error> {
+        Settings::from_env()?.auth_config()?.auth().await
     }
 
     pub fn new_with_channel(channel: Channel) -> Auth {
+        let interceptor = CallInterceptor::new(CallToken::Fixed(Arc::from("")));
         Auth {
+            clients: Arc::new(AuthClients::new(&channel, interceptor)),
             channel,
-            auth_token: String::new()
+            auth_token: String::new(),
+            transport_key: Arc::new(Mutex::new(None)),
+            plaintext_password: true,
+            options: Arc::new(CallOptions::default()),
+            retry: RetryPolicy::default(),
+            timeout: Some(DEFAULT_TIMEOUT)
         }
     }
 
     pub fn with_token(mut self, auth_token: &str) -> Self {
         self.auth_token = auth_token.to_owned();
+        let interceptor = CallInterceptor::new(CallToken::Fixed(Arc::from(auth_token)));
+        self.clients = Arc::new(AuthClients::new(&self.channel, interceptor));
+        self
+    }
+
+    // generated clients are shared with the returned client, options are applied by the interceptor on every call
+    pub fn with_options(&self, options: CallOptions) -> Self {
+        Auth {
+            options: Arc::new(options),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // user and api password is sent as plain string, only login password is encrypted with transport key
+    pub fn with_plaintext_password(mut self, allow: bool) -> Self {
+        self.plaintext_password = allow;
         self
     }
 
+    fn check_password(&self, password: Option<&str>)
+        -> Result<(), Status>
+    {
+        if password.is_some() && !self.plaintext_password {
+            return Err(Status::failed_precondition(PLAINTEXT_PASSWORD));
+        }
+        Ok(())
+    }
+
+    // timeout in call options override the default timeout
+    async fn call<T, Fut>(&self, future: Fut)
+        -> Result<T, Status>
+        where Fut: Future<Output = Result<T, Status>>
+    {
+        with_call_options(&self.options, deadline(self.options.timeout.or(self.timeout), future)).await
+    }
+
+    async fn call_retry<T, F, Fut>(&self, f: F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        self.retry.run(|| self.call(f()), |_| None).await
+    }
+
+    // mutation is only retried when explicitly called with idempotency guard
+    pub async fn call_idempotent<T, F, Fut, G>(&self, f: F, guard: G)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>, G: Fn(&Status) -> Option<T>
+    {
+        self.retry.run(|| self.call(f()), guard).await
+    }
+
     pub async fn user_login(&self, username: &str, password: &str)
         -> Result<UserLoginResponse, Status>
     {
-        auth::user_login(&self, username, password).await
+        self.call(auth::user_login(self, username, password)).await
     }
 
     pub async fn user_refresh(&self, api_id: Uuid, access_token: &str, refresh_token: &str)
         -> Result<UserRefreshResponse, Status>
     {
-        auth::user_refresh(&self, api_id, access_token, refresh_token).await
+        self.call(auth::user_refresh(self, api_id, access_token, refresh_token)).await
     }
 
     pub async fn user_logout(&self, user_id: Uuid, auth_token: &str)
         -> Result<UserLogoutResponse, Status>
     {
-        auth::user_logout(&self, user_id, auth_token).await
+        self.call(auth::user_logout(self, user_id, auth_token)).await
     }
 
     pub async fn read_api(&self, id: Uuid)
         -> Result<ApiSchema, Status>
     {
-        api::read_api(&self, id)
+        self.call_retry(|| api::read_api(self, id))
         .await
         .map(|s| s.into())
     }
@@ -81,7 +222,7 @@ impl Auth {
     pub async fn read_api_by_name(&self, name: &str)
         -> Result<ApiSchema, Status>
     {
-        api::read_api_by_name(&self, name)
+        self.call_retry(|| api::read_api_by_name(self, name))
         .await
         .map(|s| s.into())
     }
@@ -89,7 +230,7 @@ impl Auth {
     pub async fn list_api_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<ApiSchema>, Status>
     {
-        api::list_api_by_ids(&self, ids)
+        self.call_retry(|| api::list_api_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -97,7 +238,7 @@ impl Auth {
     pub async fn list_api_by_name(&self, name: &str)
         -> Result<Vec<ApiSchema>, Status>
     {
-        api::list_api_by_name(&self, name)
+        self.call_retry(|| api::list_api_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -105,7 +246,7 @@ impl Auth {
     pub async fn list_api_by_category(&self, category: &str)
         -> Result<Vec<ApiSchema>, Status>
     {
-        api::list_api_by_category(&self, category)
+        self.call_retry(|| api::list_api_by_category(self, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -113,7 +254,7 @@ impl Auth {
     pub async fn list_api_option(&self, name: Option<&str>, category: Option<&str>)
         -> Result<Vec<ApiSchema>, Status>
     {
-        api::list_api_option(&self, name, category)
+        self.call_retry(|| api::list_api_option(self, name, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -121,28 +262,37 @@ impl Auth {
     pub async fn create_api(&self, id: Uuid, name: &str, address: &str, category: &str, description: &str, password: &str, access_key: &[u8])
         -> Result<Uuid, Status>
     {
-        api::create_api(&self, id, name, address, category, description, password, access_key)
+        self.check_password(Some(password))?;
+        self.call(api::create_api(self, id, name, address, category, description, password, access_key))
         .await
     }
 
     pub async fn update_api(&self, id: Uuid, name: Option<&str>, address: Option<&str>, category: Option<&str>, description: Option<&str>, password: Option<&str>, access_key: Option<&[u8]>)
         -> Result<(), Status>
     {
-        api::update_api(&self, id, name, address, category, description, password, access_key)
+        self.check_password(password)?;
+        self.call(api::update_api(self, id, name, address, category, description, password, access_key))
+        .await
+    }
+
+    pub async fn rotate_api_key(&self, api_id: Uuid, revoke: TokenRevoke)
+        -> Result<KeyRotation, Status>
+    {
+        rotate::rotate_api_key(&self, api_id, revoke)
         .await
     }
 
     pub async fn delete_api(&self, id: Uuid)
         -> Result<(), Status>
     {
-        api::delete_api(&self, id)
+        self.call(api::delete_api(self, id))
         .await
     }
 
     pub async fn read_procedure(&self, id: Uuid)
         -> Result<ProcedureSchema, Status>
     {
-        api::read_procedure(&self, id)
+        self.call_retry(|| api::read_procedure(self, id))
         .await
         .map(|s| s.into())
     }
@@ -150,7 +300,7 @@ impl Auth {
     pub async fn read_procedure_by_name(&self, api_id: Uuid, name: &str)
         -> Result<ProcedureSchema, Status>
     {
-        api::read_procedure_by_name(&self, api_id, name)
+        self.call_retry(|| api::read_procedure_by_name(self, api_id, name))
         .await
         .map(|s| s.into())
     }
@@ -158,7 +308,7 @@ impl Auth {
     pub async fn list_procedure_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<ProcedureSchema>, Status>
     {
-        api::list_procedure_by_ids(&self, ids)
+        self.call_retry(|| api::list_procedure_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -166,7 +316,7 @@ impl Auth {
     pub async fn list_procedure_by_api(&self, api_id: Uuid)
         -> Result<Vec<ProcedureSchema>, Status>
     {
-        api::list_procedure_by_api(&self, api_id)
+        self.call_retry(|| api::list_procedure_by_api(self, api_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -174,7 +324,7 @@ impl Auth {
     pub async fn list_procedure_by_name(&self, name: &str)
         -> Result<Vec<ProcedureSchema>, Status>
     {
-        api::list_procedure_by_name(&self, name)
+        self.call_retry(|| api::list_procedure_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -182,7 +332,7 @@ impl Auth {
     pub async fn list_procedure_option(&self, api_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<ProcedureSchema>, Status>
     {
-        api::list_procedure_option(&self, api_id, name)
+        self.call_retry(|| api::list_procedure_option(self, api_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -190,28 +340,35 @@ impl Auth {
     pub async fn create_procedure(&self, id: Uuid, api_id: Uuid, name: &str, description: &str)
         -> Result<Uuid, Status>
     {
-        api::create_procedure(&self, id, api_id, name, description)
+        self.call(api::create_procedure(self, id, api_id, name, description))
         .await
     }
 
     pub async fn update_procedure(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        api::update_procedure(&self, id, name, description)
+        self.call(api::update_procedure(self, id, name, description))
         .await
     }
 
     pub async fn delete_procedure(&self, id: Uuid)
         -> Result<(), Status>
     {
-        api::delete_procedure(&self, id)
+        self.call(api::delete_procedure(self, id))
+        .await
+    }
+
+    pub async fn sync_procedure(&self, api_id: Uuid, descriptor: &[u8], options: &SyncOptions)
+        -> Result<SyncReport, Error>
+    {
+        sync::sync_procedure(&self, api_id, descriptor, options)
         .await
     }
 
     pub async fn read_role(&self, id: Uuid)
         -> Result<RoleSchema, Status>
     {
-        role::read_role(&self, id)
+        self.call_retry(|| role::read_role(self, id))
         .await
         .map(|s| s.into())
     }
@@ -219,7 +376,7 @@ impl Auth {
     pub async fn read_role_by_name(&self, api_id: Uuid, name: &str)
         -> Result<RoleSchema, Status>
     {
-        role::read_role_by_name(&self, api_id, name)
+        self.call_retry(|| role::read_role_by_name(self, api_id, name))
         .await
         .map(|s| s.into())
     }
@@ -227,7 +384,7 @@ impl Auth {
     pub async fn list_role_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<RoleSchema>, Status>
     {
-        role::list_role_by_ids(&self, ids)
+        self.call_retry(|| role::list_role_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -235,7 +392,7 @@ impl Auth {
     pub async fn list_role_by_api(&self, api_id: Uuid)
         -> Result<Vec<RoleSchema>, Status>
     {
-        role::list_role_by_api(&self, api_id)
+        self.call_retry(|| role::list_role_by_api(self, api_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -243,7 +400,7 @@ impl Auth {
     pub async fn list_role_by_user(&self, user_id: Uuid)
         -> Result<Vec<RoleSchema>, Status>
     {
-        role::list_role_by_user(&self, user_id)
+        self.call_retry(|| role::list_role_by_user(self, user_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -251,7 +408,7 @@ impl Auth {
     pub async fn list_role_by_name(&self, name: &str)
         -> Result<Vec<RoleSchema>, Status>
     {
-        role::list_role_by_name(&self, name)
+        self.call_retry(|| role::list_role_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -259,7 +416,7 @@ impl Auth {
     pub async fn list_role_option(&self, api_id: Option<Uuid>, user_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<RoleSchema>, Status>
     {
-        role::list_role_option(&self, api_id, user_id, name)
+        self.call_retry(|| role::list_role_option(self, api_id, user_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -267,42 +424,42 @@ impl Auth {
     pub async fn create_role(&self, id: Uuid, api_id: Uuid, name: &str, multi: bool, ip_lock: bool, access_duration: i32, refresh_duration: i32)
         -> Result<Uuid, Status>
     {
-        role::create_role(&self, id, api_id, name, multi, ip_lock, access_duration, refresh_duration)
+        self.call(role::create_role(self, id, api_id, name, multi, ip_lock, access_duration, refresh_duration))
         .await
     }
 
     pub async fn update_role(&self, id: Uuid, name: Option<&str>, multi: Option<bool>, ip_lock: Option<bool>, access_duration: Option<i32>, refresh_duration: Option<i32>)
         -> Result<(), Status>
     {
-        role::update_role(&self, id, name, multi, ip_lock, access_duration, refresh_duration)
+        self.call(role::update_role(self, id, name, multi, ip_lock, access_duration, refresh_duration))
         .await
     }
 
     pub async fn delete_role(&self, id: Uuid)
         -> Result<(), Status>
     {
-        role::delete_role(&self, id)
+        self.call(role::delete_role(self, id))
         .await
     }
 
     pub async fn add_role_access(&self, id: Uuid, procedure_id: Uuid)
         -> Result<(), Status>
     {
-        role::add_role_access(&self, id, procedure_id)
+        self.call(role::add_role_access(self, id, procedure_id))
         .await
     }
 
     pub async fn remove_role_access(&self, id: Uuid, procedure_id: Uuid)
         -> Result<(), Status>
     {
-        role::remove_role_access(&self, id, procedure_id)
+        self.call(role::remove_role_access(self, id, procedure_id))
         .await
     }
 
     pub async fn read_role_profile(&self, id: i32)
         -> Result<RoleProfileSchema, Status>
     {
-        profile::read_role_profile(&self, id)
+        self.call_retry(|| profile::read_role_profile(self, id))
         .await
         .map(|s| s.into())
     }
@@ -310,7 +467,7 @@ impl Auth {
     pub async fn list_role_profile_by_role(&self, role_id: Uuid)
         -> Result<Vec<RoleProfileSchema>, Status>
     {
-        profile::list_role_profile_by_role(&self, role_id)
+        self.call_retry(|| profile::list_role_profile_by_role(self, role_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -318,28 +475,28 @@ impl Auth {
     pub async fn create_role_profile(&self, role_id: Uuid, name: &str, value_type: DataType, mode: ProfileMode)
         -> Result<i32, Status>
     {
-        profile::create_role_profile(&self, role_id, name, value_type, mode)
+        self.call(profile::create_role_profile(self, role_id, name, value_type, mode))
         .await
     }
 
     pub async fn update_role_profile(&self, id: i32, name: Option<&str>, value_type: Option<DataType>, mode: Option<ProfileMode>)
         -> Result<(), Status>
     {
-        profile::update_role_profile(&self, id, name, value_type, mode)
+        self.call(profile::update_role_profile(self, id, name, value_type, mode))
         .await
     }
 
     pub async fn delete_role_profile(&self, id: i32)
         -> Result<(), Status>
     {
-        profile::delete_role_profile(&self, id)
+        self.call(profile::delete_role_profile(self, id))
         .await
     }
 
     pub async fn read_user(&self, id: Uuid)
         -> Result<UserSchema, Status>
     {
-        user::read_user(&self, id)
+        self.call_retry(|| user::read_user(self, id))
         .await
         .map(|s| s.into())
     }
@@ -347,7 +504,7 @@ impl Auth {
     pub async fn read_user_by_name(&self, name: &str)
         -> Result<UserSchema, Status>
     {
-        user::read_user_by_name(&self, name)
+        self.call_retry(|| user::read_user_by_name(self, name))
         .await
         .map(|s| s.into())
     }
@@ -355,7 +512,7 @@ impl Auth {
     pub async fn list_user_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<UserSchema>, Status>
     {
-        user::list_user_by_ids(&self, ids)
+        self.call_retry(|| user::list_user_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -363,7 +520,7 @@ impl Auth {
     pub async fn list_user_by_api(&self, api_id: Uuid)
         -> Result<Vec<UserSchema>, Status>
     {
-        user::list_user_by_api(&self, api_id)
+        self.call_retry(|| user::list_user_by_api(self, api_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -371,7 +528,7 @@ impl Auth {
     pub async fn list_user_by_role(&self, role_id: Uuid)
         -> Result<Vec<UserSchema>, Status>
     {
-        user::list_user_by_role(&self, role_id)
+        self.call_retry(|| user::list_user_by_role(self, role_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -379,7 +536,7 @@ impl Auth {
     pub async fn list_user_by_name(&self, name: &str)
         -> Result<Vec<UserSchema>, Status>
     {
-        user::list_user_by_name(&self, name)
+        self.call_retry(|| user::list_user_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -387,7 +544,7 @@ impl Auth {
     pub async fn list_user_option(&self, api_id: Option<Uuid>, role_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<UserSchema>, Status>
     {
-        user::list_user_option(&self, api_id, role_id, name)
+        self.call_retry(|| user::list_user_option(self, api_id, role_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -395,42 +552,44 @@ impl Auth {
     pub async fn create_user(&self, id: Uuid, name: &str, email: &str, phone: &str, password: &str)
         -> Result<Uuid, Status>
     {
-        user::create_user(&self, id, name, email, phone, password)
+        self.check_password(Some(password))?;
+        self.call(user::create_user(self, id, name, email, phone, password))
         .await
     }
 
     pub async fn update_user(&self, id: Uuid, name: Option<&str>, email: Option<&str>, phone: Option<&str>, password: Option<&str>)
         -> Result<(), Status>
     {
-        user::update_user(&self, id, name, email, phone, password)
+        self.check_password(password)?;
+        self.call(user::update_user(self, id, name, email, phone, password))
         .await
     }
 
     pub async fn delete_user(&self, id: Uuid)
         -> Result<(), Status>
     {
-        user::delete_user(&self, id)
+        self.call(user::delete_user(self, id))
         .await
     }
 
     pub async fn add_user_role(&self, id: Uuid, role_id: Uuid)
         -> Result<(), Status>
     {
-        user::add_user_role(&self, id, role_id)
+        self.call(user::add_user_role(self, id, role_id))
         .await
     }
 
     pub async fn remove_user_role(&self, id: Uuid, role_id: Uuid)
         -> Result<(), Status>
     {
-        user::remove_user_role(&self, id, role_id)
+        self.call(user::remove_user_role(self, id, role_id))
         .await
     }
 
     pub async fn read_user_profile(&self, id: i32)
         -> Result<UserProfileSchema, Status>
     {
-        profile::read_user_profile(&self, id)
+        self.call_retry(|| profile::read_user_profile(self, id))
         .await
         .map(|s| s.into())
     }
@@ -438,7 +597,7 @@ impl Auth {
     pub async fn list_user_profile_by_user(&self, user_id: Uuid)
         -> Result<Vec<UserProfileSchema>, Status>
     {
-        profile::list_user_profile_by_user(&self, user_id)
+        self.call_retry(|| profile::list_user_profile_by_user(self, user_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -446,35 +605,70 @@ impl Auth {
     pub async fn create_user_profile(&self, user_id: Uuid, name: &str, value: DataValue)
         -> Result<i32, Status>
     {
-        profile::create_user_profile(&self, user_id, name, value)
+        self.call(profile::create_user_profile(self, user_id, name, value))
         .await
     }
 
     pub async fn update_user_profile(&self, id: i32, name: Option<&str>, value: Option<DataValue>)
         -> Result<(), Status>
     {
-        profile::update_user_profile(&self, id, name, value)
+        self.call(profile::update_user_profile(self, id, name, value))
+        .await
+    }
+
+    pub async fn validate_user_profile(&self, user_id: Uuid, profiles: &[UserProfileSchema])
+        -> Result<Vec<ProfileError>, Status>
+    {
+        validator::validate_user_profile(&self, user_id, profiles)
         .await
     }
 
+    pub async fn create_user_profile_checked(&self, user_id: Uuid, name: &str, value: DataValue)
+        -> Result<i32, Error>
+    {
+        let change = UserProfileSchema { id: 0, user_id, name: name.to_owned(), value: value.clone(), order: 0 };
+        let errors = validator::validate_user_profile_change(&self, user_id, change).await?;
+        if !errors.is_empty() {
+            return Err(Error::Profile(errors));
+        }
+        Ok(self.call(profile::create_user_profile(self, user_id, name, value)).await?)
+    }
+
+    pub async fn update_user_profile_checked(&self, id: i32, name: Option<&str>, value: Option<DataValue>)
+        -> Result<(), Error>
+    {
+        let mut change = self.read_user_profile(id).await?;
+        if let Some(name) = name {
+            change.name = name.to_owned();
+        }
+        if let Some(value) = value.clone() {
+            change.value = value;
+        }
+        let errors = validator::validate_user_profile_change(&self, change.user_id, change).await?;
+        if !errors.is_empty() {
+            return Err(Error::Profile(errors));
+        }
+        Ok(self.call(profile::update_user_profile(self, id, name, value)).await?)
+    }
+
     pub async fn delete_user_profile(&self, id: i32)
         -> Result<(), Status>
     {
-        profile::delete_user_profile(&self, id)
+        self.call(profile::delete_user_profile(self, id))
         .await
     }
 
     pub async fn swap_user_profile(&self, user_id: Uuid, name: &str, order_1: i16, order_2: i16)
         -> Result<(), Status>
     {
-        profile::swap_user_profile(&self, user_id, name, order_1, order_2)
+        self.call(profile::swap_user_profile(self, user_id, name, order_1, order_2))
         .await
     }
 
     pub async fn read_access_token(&self, access_id: i32)
         -> Result<TokenSchema, Status>
     {
-        token::read_access_token(&self, access_id)
+        self.call_retry(|| token::read_access_token(self, access_id))
         .await
         .map(|s| s.into())
     }
@@ -482,7 +676,7 @@ impl Auth {
     pub async fn list_auth_token(&self, refresh_token: &str)
         -> Result<Vec<TokenSchema>, Status>
     {
-        token::list_auth_token(&self, refresh_token)
+        self.call_retry(|| token::list_auth_token(self, refresh_token))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -490,57 +684,99 @@ impl Auth {
     pub async fn list_token_by_user(&self, user_id: Uuid)
         -> Result<Vec<TokenSchema>, Status>
     {
-        token::list_token_by_user(&self, user_id)
+        self.call_retry(|| token::list_token_by_user(self, user_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
-    pub async fn create_access_token(&self, user_id: Uuid, auth_token: &str, expire: DateTime<Utc>, ip: &[u8])
+    pub async fn create_access_token(&self, user_id: Uuid, auth_token: &str, expire: DateTime<Utc>, ip: Option<IpAddr>)
         -> Result<(i32, String, String), Status>
     {
-        token::create_access_token(&self, user_id, auth_token, expire, ip)
+        self.call(token::create_access_token(self, user_id, auth_token, expire, ip))
         .await
     }
 
-    pub async fn create_auth_token(&self, user_id: Uuid, expire: DateTime<Utc>, ip: &[u8], number: u32)
+    pub async fn create_auth_token(&self, user_id: Uuid, expire: DateTime<Utc>, ip: Option<IpAddr>, number: u32)
         -> Result<Vec<(i32, String, String)>, Status>
     {
-        token::create_auth_token(&self, user_id, expire, ip, number)
+        self.call(token::create_auth_token(self, user_id, expire, ip, number))
         .await
     }
 
-    pub async fn update_access_token(&self, access_id: i32, expire: Option<DateTime<Utc>>, ip: Option<&[u8]>)
+    pub async fn update_access_token(&self, access_id: i32, expire: Option<DateTime<Utc>>, ip: IpUpdate)
         -> Result<(String, String), Status>
     {
-        token::update_access_token(&self, access_id, expire, ip)
+        self.call(token::update_access_token(self, access_id, expire, ip))
         .await
     }
 
-    pub async fn update_auth_token(&self, auth_token: &str, expire: Option<DateTime<Utc>>, ip: Option<&[u8]>)
+    pub async fn update_auth_token(&self, auth_token: &str, expire: Option<DateTime<Utc>>, ip: IpUpdate)
         -> Result<(String, String), Status>
     {
-        token::update_auth_token(&self, auth_token, expire, ip)
+        self.call(token::update_auth_token(self, auth_token, expire, ip))
         .await
     }
 
     pub async fn delete_access_token(&self, access_id: i32)
         -> Result<(), Status>
     {
-        token::delete_access_token(&self, access_id)
+        self.call(token::delete_access_token(self, access_id))
         .await
     }
 
     pub async fn delete_auth_token(&self, auth_token: &str)
         -> Result<(), Status>
     {
-        token::delete_auth_token(&self, auth_token)
+        self.call(token::delete_auth_token(self, auth_token))
         .await
     }
 
     pub async fn delete_token_by_user(&self, user_id: Uuid)
         -> Result<(), Status>
     {
-        token::delete_token_by_user(&self, user_id)
+        self.call(token::delete_token_by_user(self, user_id))
+        .await
+    }
+
+    pub async fn audit_token(&self, options: &AuditOptions)
+        -> Result<TokenAuditReport, Status>
+    {
+        audit::audit_token(&self, options)
+        .await
+    }
+
+    pub async fn audit_user_token(&self, user_id: Uuid, options: &AuditOptions)
+        -> Result<TokenAuditReport, Status>
+    {
+        audit::audit_user_token(&self, user_id, options)
+        .await
+    }
+
+    pub async fn revoke_token(&self, report: &TokenAuditReport, statuses: &[TokenStatus])
+        -> Result<RevokeReport, Status>
+    {
+        audit::revoke_token(&self, report, statuses)
+        .await
+    }
+
+    pub async fn delete_user_cascade(&self, id: Uuid, dry_run: bool)
+        -> Result<CascadeReport, (Status, CascadeReport)>
+    {
+        cascade::delete_user_cascade(&self, id, dry_run)
+        .await
+    }
+
+    pub async fn delete_role_cascade(&self, id: Uuid, dry_run: bool)
+        -> Result<CascadeReport, (Status, CascadeReport)>
+    {
+        cascade::delete_role_cascade(&self, id, dry_run)
+        .await
+    }
+
+    pub async fn delete_api_cascade(&self, id: Uuid, dry_run: bool)
+        -> Result<CascadeReport, (Status, CascadeReport)>
+    {
+        cascade::delete_api_cascade(&self, id, dry_run)
         .await
     }
 
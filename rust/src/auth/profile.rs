@@ -2,22 +2,18 @@ use tonic::{Request, Status};
 use uuid::Uuid;
 use rmcs_auth_db::ProfileMode;
 use rmcs_resource_db::{DataType, DataValue};
-use rmcs_auth_api::profile::profile_service_client::ProfileServiceClient;
 use rmcs_auth_api::profile::{
     ProfileId, RoleId, UserId, RoleProfileSchema, RoleProfileUpdate, 
     UserProfileSchema, UserProfileUpdate, UserProfileSwap
 };
 use crate::auth::Auth;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const PROFILE_NOT_FOUND: &str = "requested profile not found";
 
 pub(crate) async fn read_role_profile(auth: &Auth, id: i32)
     -> Result<RoleProfileSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(ProfileId {
         id
     });
@@ -30,9 +26,7 @@ pub(crate) async fn read_role_profile(auth: &Auth, id: i32)
 pub(crate) async fn list_role_profile_by_role(auth: &Auth, role_id: Uuid)
     -> Result<Vec<RoleProfileSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(RoleId {
         id: role_id.as_bytes().to_vec()
     });
@@ -45,9 +39,7 @@ pub(crate) async fn list_role_profile_by_role(auth: &Auth, role_id: Uuid)
 pub(crate) async fn create_role_profile(auth: &Auth, role_id: Uuid, name: &str, value_type: DataType, mode: ProfileMode)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(RoleProfileSchema {
         id: 0,
         role_id: role_id.as_bytes().to_vec(),
@@ -64,9 +56,7 @@ pub(crate) async fn create_role_profile(auth: &Auth, role_id: Uuid, name: &str,
 pub(crate) async fn update_role_profile(auth: &Auth, id: i32, name: Option<&str>, value_type: Option<DataType>, mode: Option<ProfileMode>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(RoleProfileUpdate {
         id,
         name: name.map(|s| s.to_owned()),
@@ -81,9 +71,7 @@ pub(crate) async fn update_role_profile(auth: &Auth, id: i32, name: Option<&str>
 pub(crate) async fn delete_role_profile(auth: &Auth, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(ProfileId {
         id
     });
@@ -95,9 +83,7 @@ pub(crate) async fn delete_role_profile(auth: &Auth, id: i32)
 pub(crate) async fn read_user_profile(auth: &Auth, id: i32)
     -> Result<UserProfileSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(ProfileId {
         id
     });
@@ -110,9 +96,7 @@ pub(crate) async fn read_user_profile(auth: &Auth, id: i32)
 pub(crate) async fn list_user_profile_by_user(auth: &Auth, user_id: Uuid)
     -> Result<Vec<UserProfileSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(UserId {
         id: user_id.as_bytes().to_vec()
     });
@@ -125,9 +109,7 @@ pub(crate) async fn list_user_profile_by_user(auth: &Auth, user_id: Uuid)
 pub(crate) async fn create_user_profile(auth: &Auth, user_id: Uuid, name: &str, value: DataValue)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(UserProfileSchema {
         id: 0,
         user_id: user_id.as_bytes().to_vec(),
@@ -145,9 +127,7 @@ pub(crate) async fn create_user_profile(auth: &Auth, user_id: Uuid, name: &str,
 pub(crate) async fn update_user_profile(auth: &Auth, id: i32, name: Option<&str>, value: Option<DataValue>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(UserProfileUpdate {
         id,
         name: name.map(|s| s.to_owned()),
@@ -162,9 +142,7 @@ pub(crate) async fn update_user_profile(auth: &Auth, id: i32, name: Option<&str>
 pub(crate) async fn delete_user_profile(auth: &Auth, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(ProfileId {
         id
     });
@@ -176,9 +154,7 @@ pub(crate) async fn delete_user_profile(auth: &Auth, id: i32)
 pub(crate) async fn swap_user_profile(auth: &Auth, user_id: Uuid, name: &str, order_1: i16, order_2: i16)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        ProfileServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.profile.clone();
     let request = Request::new(UserProfileSwap {
         user_id: user_id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -1,20 +1,16 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_auth_api::role::role_service_client::RoleServiceClient;
 use rmcs_auth_api::role::{
     RoleId, RoleIds, RoleName, ApiId, UserId, RoleOption, RoleSchema, RoleUpdate, RoleAccess
 };
 use crate::auth::Auth;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const ROLE_NOT_FOUND: &str = "requested role not found";
 
 pub(crate) async fn read_role(auth: &Auth, id: Uuid)
     -> Result<RoleSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleId {
         id: id.as_bytes().to_vec()
     });
@@ -27,9 +23,7 @@ pub(crate) async fn read_role(auth: &Auth, id: Uuid)
 pub(crate) async fn read_role_by_name(auth: &Auth, api_id: Uuid, name: &str)
     -> Result<RoleSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleName {
         api_id: api_id.as_bytes().to_vec(),
         name: name.to_owned()
@@ -43,9 +37,7 @@ pub(crate) async fn read_role_by_name(auth: &Auth, api_id: Uuid, name: &str)
 pub(crate) async fn list_role_by_ids(auth: &Auth, ids: &[Uuid])
     -> Result<Vec<RoleSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -58,9 +50,7 @@ pub(crate) async fn list_role_by_ids(auth: &Auth, ids: &[Uuid])
 pub(crate) async fn list_role_by_api(auth: &Auth, api_id: Uuid)
     -> Result<Vec<RoleSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(ApiId {
         api_id: api_id.as_bytes().to_vec()
     });
@@ -73,9 +63,7 @@ pub(crate) async fn list_role_by_api(auth: &Auth, api_id: Uuid)
 pub(crate) async fn list_role_by_user(auth: &Auth, user_id: Uuid)
     -> Result<Vec<RoleSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(UserId {
         user_id: user_id.as_bytes().to_vec()
     });
@@ -88,9 +76,7 @@ pub(crate) async fn list_role_by_user(auth: &Auth, user_id: Uuid)
 pub(crate) async fn list_role_by_name(auth: &Auth, name: &str)
     -> Result<Vec<RoleSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleName {
         api_id: Uuid::nil().as_bytes().to_vec(),
         name: name.to_owned()
@@ -104,9 +90,7 @@ pub(crate) async fn list_role_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_role_option(auth: &Auth, api_id: Option<Uuid>, user_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<RoleSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleOption {
         api_id: api_id.map(|id| id.as_bytes().to_vec()),
         user_id: user_id.map(|id| id.as_bytes().to_vec()),
@@ -121,9 +105,7 @@ pub(crate) async fn list_role_option(auth: &Auth, api_id: Option<Uuid>, user_id:
 pub(crate) async fn create_role(auth: &Auth, id: Uuid, api_id: Uuid, name: &str, multi: bool, ip_lock: bool, access_duration: i32, refresh_duration: i32)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleSchema {
         id: id.as_bytes().to_vec(),
         api_id: api_id.as_bytes().to_vec(),
@@ -144,9 +126,7 @@ pub(crate) async fn create_role(auth: &Auth, id: Uuid, api_id: Uuid, name: &str,
 pub(crate) async fn update_role(auth: &Auth, id: Uuid, name: Option<&str>, multi: Option<bool>, ip_lock: Option<bool>, access_duration: Option<i32>, refresh_duration: Option<i32>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -164,9 +144,7 @@ pub(crate) async fn update_role(auth: &Auth, id: Uuid, name: Option<&str>, multi
 pub(crate) async fn delete_role(auth: &Auth, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleId {
         id: id.as_bytes().to_vec()
     });
@@ -179,9 +157,7 @@ pub(crate) async fn delete_role(auth: &Auth, id: Uuid)
 pub(crate) async fn add_role_access(auth: &Auth, id: Uuid, procedure_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleAccess {
         id: id.as_bytes().to_vec(),
         procedure_id: procedure_id.as_bytes().to_vec()
@@ -195,9 +171,7 @@ pub(crate) async fn add_role_access(auth: &Auth, id: Uuid, procedure_id: Uuid)
 pub(crate) async fn remove_role_access(auth: &Auth, id: Uuid, procedure_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        RoleServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.role.clone();
     let request = Request::new(RoleAccess {
         id: id.as_bytes().to_vec(),
         procedure_id: procedure_id.as_bytes().to_vec()
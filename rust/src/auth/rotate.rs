@@ -0,0 +1,72 @@
+use tonic::Status;
+use uuid::Uuid;
+use crate::auth::Auth;
+use crate::auth::utility::generate_access_key;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleKeyRotation {
+    pub role_id: Uuid,
+    pub old_key: Vec<u8>,
+    pub new_key: Vec<u8>
+}
+
+// token schema has no api information, so revoking tokens of a user also revoke the user tokens for other apis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TokenRevoke {
+    #[default]
+    Keep,
+    // revoke tokens of every user having a role in the api
+    All,
+    // skip users also having roles in other apis, they are listed in skipped users of the rotation
+    SkipShared
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRotation {
+    pub api_id: Uuid,
+    pub old_key: Vec<u8>,
+    pub new_key: Vec<u8>,
+    pub roles: Vec<RoleKeyRotation>,
+    pub revoked_tokens: Vec<i32>,
+    pub skipped_users: Vec<Uuid>
+}
+
+pub(crate) async fn rotate_api_key(auth: &Auth, api_id: Uuid, revoke: TokenRevoke)
+    -> Result<KeyRotation, Status>
+{
+    let api = auth.read_api(api_id).await?;
+    let roles_old = auth.list_role_by_api(api_id).await?;
+    let new_key = generate_access_key();
+    auth.update_api(api_id, None, None, None, None, None, Some(&new_key)).await?;
+    // role access keys are derived from api access key so they are changed too
+    let roles_new = auth.list_role_by_api(api_id).await?;
+    let roles = roles_old.into_iter()
+        .map(|old| RoleKeyRotation {
+            role_id: old.id,
+            new_key: roles_new.iter().find(|r| r.id == old.id).map(|r| r.access_key.clone()).unwrap_or_default(),
+            old_key: old.access_key
+        })
+        .collect();
+    let mut revoked_tokens = Vec::new();
+    let mut skipped_users = Vec::new();
+    if revoke != TokenRevoke::Keep {
+        for user in auth.list_user_by_api(api_id).await? {
+            if revoke == TokenRevoke::SkipShared && user.roles.iter().any(|r| r.api_id != api_id) {
+                skipped_users.push(user.id);
+                continue;
+            }
+            for token in auth.list_token_by_user(user.id).await? {
+                auth.delete_access_token(token.access_id).await?;
+                revoked_tokens.push(token.access_id);
+            }
+        }
+    }
+    Ok(KeyRotation {
+        api_id,
+        old_key: api.access_key,
+        new_key,
+        roles,
+        revoked_tokens,
+        skipped_users
+    })
+}
@@ -0,0 +1,102 @@
+use prost::Message;
+use prost_types::FileDescriptorSet;
+use uuid::Uuid;
+use crate::auth::Auth;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default)]
+pub struct SyncOptions {
+    pub services: Vec<String>,
+    pub snake_case: bool,
+    pub dry_run: bool
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    pub dry_run: bool,
+    pub created: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: Vec<String>
+}
+
+// run of capital letters is one word, the last capital starts a new word when followed by lowercase letter
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_lower = i > 0 && !chars[i - 1].is_uppercase();
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if i > 0 && (prev_lower || next_lower) {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(*c);
+        }
+    }
+    snake
+}
+
+// services can be filtered by its name or full name including the package,
+// unknown service or empty method list is rejected since syncing it would delete every procedure
+pub fn descriptor_methods(descriptor: &[u8], options: &SyncOptions) -> Result<Vec<String>, Error> {
+    let descriptor_set = FileDescriptorSet::decode(descriptor)
+        .map_err(|e| Error::Parse(e.to_string()))?;
+    let mut methods = Vec::new();
+    let mut matched = Vec::new();
+    for file in descriptor_set.file {
+        for service in &file.service {
+            let name = service.name().to_owned();
+            let full_name = format!("{}.{}", file.package(), name);
+            let selected: Vec<&String> = options.services.iter().filter(|s| *s == &name || *s == &full_name).collect();
+            if !options.services.is_empty() && selected.is_empty() {
+                continue;
+            }
+            matched.extend(selected);
+            for method in &service.method {
+                let method_name = if options.snake_case { snake_case(method.name()) } else { method.name().to_owned() };
+                if !methods.contains(&method_name) {
+                    methods.push(method_name);
+                }
+            }
+        }
+    }
+    if let Some(service) = options.services.iter().find(|s| !matched.contains(s)) {
+        return Err(Error::InvalidArgument(format!("service {} is not found in descriptor", service)));
+    }
+    if methods.is_empty() {
+        return Err(Error::InvalidArgument("no method is found in descriptor".to_owned()));
+    }
+    Ok(methods)
+}
+
+pub(crate) async fn sync_procedure(auth: &Auth, api_id: Uuid, descriptor: &[u8], options: &SyncOptions)
+    -> Result<SyncReport, Error>
+{
+    let methods = descriptor_methods(descriptor, options)?;
+    let procedures = auth.list_procedure_by_api(api_id).await?;
+    let mut report = SyncReport { dry_run: options.dry_run, ..Default::default() };
+    for method in &methods {
+        if procedures.iter().any(|p| &p.name == method) {
+            report.unchanged.push(method.clone());
+            continue;
+        }
+        if !options.dry_run {
+            auth.create_procedure(Uuid::new_v4(), api_id, method, "").await?;
+        }
+        report.created.push(method.clone());
+    }
+    let roles = auth.list_role_by_api(api_id).await?;
+    for procedure in procedures.iter().filter(|p| !methods.contains(&p.name)) {
+        if !options.dry_run {
+            // procedure can only be deleted after unlinked from all roles
+            for role in roles.iter().filter(|r| r.procedures.contains(&procedure.id)) {
+                auth.remove_role_access(role.id, procedure.id).await?;
+            }
+            auth.delete_procedure(procedure.id).await?;
+        }
+        report.deleted.push(procedure.name.clone());
+    }
+    Ok(report)
+}
@@ -1,21 +1,19 @@
 use tonic::{Request, Status};
 use chrono::{DateTime, Utc};
+use std::net::IpAddr;
 use uuid::Uuid;
-use rmcs_auth_api::token::token_service_client::TokenServiceClient;
 use rmcs_auth_api::token::{
     TokenSchema, AccessId, AuthToken, UserId, AuthTokenCreate, TokenUpdate
 };
-use crate::auth::Auth;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
+use crate::auth::{Auth, IpUpdate};
+use crate::auth::ip::ip_to_bytes;
 
 const TOKEN_NOT_FOUND: &str = "requested token not found";
 
 pub(crate) async fn read_access_token(auth: &Auth, access_id: i32)
     -> Result<TokenSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(AccessId {
         access_id
     });
@@ -28,9 +26,7 @@ pub(crate) async fn read_access_token(auth: &Auth, access_id: i32)
 pub(crate) async fn list_auth_token(auth: &Auth, auth_token: &str)
     -> Result<Vec<TokenSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(AuthToken {
         auth_token: auth_token.to_owned()
     });
@@ -43,9 +39,7 @@ pub(crate) async fn list_auth_token(auth: &Auth, auth_token: &str)
 pub(crate) async fn list_token_by_user(auth: &Auth, user_id: Uuid)
     -> Result<Vec<TokenSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(UserId {
         user_id: user_id.as_bytes().to_vec()
     });
@@ -55,19 +49,17 @@ pub(crate) async fn list_token_by_user(auth: &Auth, user_id: Uuid)
     Ok(response.results)
 }
 
-pub(crate) async fn create_access_token(auth: &Auth, user_id: Uuid, auth_token: &str, expire: DateTime<Utc>, ip: &[u8])
+pub(crate) async fn create_access_token(auth: &Auth, user_id: Uuid, auth_token: &str, expire: DateTime<Utc>, ip: Option<IpAddr>)
     -> Result<(i32, String, String), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(TokenSchema {
         access_id: 0,
         user_id: user_id.as_bytes().to_vec(),
         refresh_token: String::new(),
         auth_token: auth_token.to_owned(),
         expire: expire.timestamp_micros(),
-        ip: ip.to_vec()
+        ip: ip_to_bytes(ip)
     });
     let response = client.create_access_token(request)
         .await?
@@ -75,17 +67,15 @@ pub(crate) async fn create_access_token(auth: &Auth, user_id: Uuid, auth_token:
     Ok((response.access_id, response.refresh_token, response.auth_token))
 }
 
-pub(crate) async fn create_auth_token(auth: &Auth, user_id: Uuid, expire: DateTime<Utc>, ip: &[u8], number: u32)
+pub(crate) async fn create_auth_token(auth: &Auth, user_id: Uuid, expire: DateTime<Utc>, ip: Option<IpAddr>, number: u32)
     -> Result<Vec<(i32, String, String)>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(AuthTokenCreate {
         user_id: user_id.as_bytes().to_vec(),
         number,
         expire: expire.timestamp_micros(),
-        ip: ip.to_vec()
+        ip: ip_to_bytes(ip)
     });
     let response = client.create_auth_token(request)
         .await?
@@ -97,18 +87,16 @@ pub(crate) async fn create_auth_token(auth: &Auth, user_id: Uuid, expire: DateTi
     )
 }
 
-pub(crate) async fn update_access_token(auth: &Auth, access_id: i32, expire: Option<DateTime<Utc>>, ip: Option<&[u8]>)
+pub(crate) async fn update_access_token(auth: &Auth, access_id: i32, expire: Option<DateTime<Utc>>, ip: IpUpdate)
     -> Result<(String, String), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(TokenUpdate {
         access_id: Some(access_id),
         refresh_token: None,
         auth_token: None,
         expire: expire.map(|s| s.timestamp_micros()),
-        ip: ip.map(|s| s.to_vec())
+        ip: ip.to_bytes()
     });
     let response = client.update_access_token(request)
         .await?
@@ -116,18 +104,16 @@ pub(crate) async fn update_access_token(auth: &Auth, access_id: i32, expire: Opt
     Ok((response.refresh_token, response.auth_token))
 }
 
-pub(crate) async fn update_auth_token(auth: &Auth, auth_token: &str, expire: Option<DateTime<Utc>>, ip: Option<&[u8]>)
+pub(crate) async fn update_auth_token(auth: &Auth, auth_token: &str, expire: Option<DateTime<Utc>>, ip: IpUpdate)
     -> Result<(String, String), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(TokenUpdate {
         access_id: None,
         refresh_token: None,
         auth_token: Some(auth_token.to_owned()),
         expire: expire.map(|s| s.timestamp_micros()),
-        ip: ip.map(|s| s.to_vec())
+        ip: ip.to_bytes()
     });
     let response = client.update_auth_token(request)
         .await?
@@ -138,9 +124,7 @@ pub(crate) async fn update_auth_token(auth: &Auth, auth_token: &str, expire: Opt
 pub(crate) async fn delete_access_token(auth: &Auth, access_id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(AccessId {
         access_id
     });
@@ -152,9 +136,7 @@ pub(crate) async fn delete_access_token(auth: &Auth, access_id: i32)
 pub(crate) async fn delete_auth_token(auth: &Auth, auth_token: &str)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(AuthToken {
         auth_token: auth_token.to_owned()
     });
@@ -166,9 +148,7 @@ pub(crate) async fn delete_auth_token(auth: &Auth, auth_token: &str)
 pub(crate) async fn delete_token_by_user(auth: &Auth, user_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        TokenServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.token.clone();
     let request = Request::new(UserId {
         user_id: user_id.as_bytes().to_vec()
     });
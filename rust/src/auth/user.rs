@@ -1,20 +1,16 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_auth_api::user::user_service_client::UserServiceClient;
 use rmcs_auth_api::user::{
     UserId, UserIds, UserName, ApiId, RoleId, UserOption, UserSchema, UserUpdate, UserRole
 };
 use crate::auth::Auth;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const USER_NOT_FOUND: &str = "requested user not found";
 
 pub(crate) async fn read_user(auth: &Auth, id: Uuid)
     -> Result<UserSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserId {
         id: id.as_bytes().to_vec()
     });
@@ -27,9 +23,7 @@ pub(crate) async fn read_user(auth: &Auth, id: Uuid)
 pub(crate) async fn read_user_by_name(auth: &Auth, name: &str)
     -> Result<UserSchema, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserName {
         name: name.to_owned()
     });
@@ -42,9 +36,7 @@ pub(crate) async fn read_user_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_user_by_ids(auth: &Auth, ids: &[Uuid])
     -> Result<Vec<UserSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -57,9 +49,7 @@ pub(crate) async fn list_user_by_ids(auth: &Auth, ids: &[Uuid])
 pub(crate) async fn list_user_by_api(auth: &Auth, api_id: Uuid)
     -> Result<Vec<UserSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(ApiId {
         id: api_id.as_bytes().to_vec()
     });
@@ -72,9 +62,7 @@ pub(crate) async fn list_user_by_api(auth: &Auth, api_id: Uuid)
 pub(crate) async fn list_user_by_role(auth: &Auth, role_id: Uuid)
     -> Result<Vec<UserSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(RoleId {
         id: role_id.as_bytes().to_vec()
     });
@@ -87,9 +75,7 @@ pub(crate) async fn list_user_by_role(auth: &Auth, role_id: Uuid)
 pub(crate) async fn list_user_by_name(auth: &Auth, name: &str)
     -> Result<Vec<UserSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserName {
         name: name.to_owned()
     });
@@ -102,9 +88,7 @@ pub(crate) async fn list_user_by_name(auth: &Auth, name: &str)
 pub(crate) async fn list_user_option(auth: &Auth, api_id: Option<Uuid>, role_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<UserSchema>, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserOption {
         api_id: api_id.map(|id| id.as_bytes().to_vec()),
         role_id: role_id.map(|id| id.as_bytes().to_vec()),
@@ -119,9 +103,7 @@ pub(crate) async fn list_user_option(auth: &Auth, api_id: Option<Uuid>, role_id:
 pub(crate) async fn create_user(auth: &Auth, id: Uuid, name: &str, email: &str, phone: &str, password: &str)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -139,9 +121,7 @@ pub(crate) async fn create_user(auth: &Auth, id: Uuid, name: &str, email: &str,
 pub(crate) async fn update_user(auth: &Auth, id: Uuid, name: Option<&str>, email: Option<&str>, phone: Option<&str>, password: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -157,9 +137,7 @@ pub(crate) async fn update_user(auth: &Auth, id: Uuid, name: Option<&str>, email
 pub(crate) async fn delete_user(auth: &Auth, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserId {
         id: id.as_bytes().to_vec()
     });
@@ -171,9 +149,7 @@ pub(crate) async fn delete_user(auth: &Auth, id: Uuid)
 pub(crate) async fn add_user_role(auth: &Auth, id: Uuid, role_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserRole {
         user_id: id.as_bytes().to_vec(),
         role_id: role_id.as_bytes().to_vec()
@@ -186,9 +162,7 @@ pub(crate) async fn add_user_role(auth: &Auth, id: Uuid, role_id: Uuid)
 pub(crate) async fn remove_user_role(auth: &Auth, id: Uuid, role_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(auth.auth_token.clone());
-    let mut client = 
-        UserServiceClient::with_interceptor(auth.channel.to_owned(), interceptor);
+    let mut client = auth.clients.user.clone();
     let request = Request::new(UserRole {
         user_id: id.as_bytes().to_vec(),
         role_id: role_id.as_bytes().to_vec()
@@ -0,0 +1,120 @@
+use std::fmt;
+use tonic::Status;
+use uuid::Uuid;
+use crate::auth::{Auth, RoleProfileSchema, UserProfileSchema, ProfileMode, DataType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProfileError {
+    Unknown { name: String },
+    TypeUnmatch { name: String, expected: DataType, found: DataType },
+    Missing { name: String },
+    Multiple { name: String },
+    Conflict { name: String, types: Vec<DataType> }
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::Unknown { name } => write!(f, "{}: not defined in user role profiles", name),
+            ProfileError::TypeUnmatch { name, expected, found } => write!(f, "{}: expected type {:?}, found {:?}", name, expected, found),
+            ProfileError::Missing { name } => write!(f, "{}: required value is missing", name),
+            ProfileError::Multiple { name } => write!(f, "{}: only single value allowed", name),
+            ProfileError::Conflict { name, types } => write!(f, "{}: user roles define different types {:?}", name, types)
+        }
+    }
+}
+
+struct ProfileRule {
+    name: String,
+    value_types: Vec<DataType>,
+    required: bool,
+    multiple: bool
+}
+
+// merge role profiles with the same name from different roles, the least restrictive mode is used
+// and all defined types are kept so the disagreement can be reported
+fn profile_rules(role_profiles: &[RoleProfileSchema]) -> Vec<ProfileRule> {
+    let mut rules: Vec<ProfileRule> = Vec::new();
+    for profile in role_profiles {
+        let required = matches!(profile.mode, ProfileMode::SingleRequired | ProfileMode::MultipleRequired);
+        let multiple = matches!(profile.mode, ProfileMode::MultipleOptional | ProfileMode::MultipleRequired);
+        match rules.iter_mut().find(|r| r.name == profile.name) {
+            Some(rule) => {
+                rule.required = rule.required && required;
+                rule.multiple = rule.multiple || multiple;
+                if !rule.value_types.contains(&profile.value_type) {
+                    rule.value_types.push(profile.value_type);
+                }
+            },
+            None => rules.push(ProfileRule {
+                name: profile.name.clone(),
+                value_types: vec![profile.value_type],
+                required,
+                multiple
+            })
+        }
+    }
+    rules
+}
+
+pub fn validate_profiles(role_profiles: &[RoleProfileSchema], user_profiles: &[UserProfileSchema])
+    -> Vec<ProfileError>
+{
+    let rules = profile_rules(role_profiles);
+    let mut errors = Vec::new();
+    for profile in user_profiles {
+        match rules.iter().find(|r| r.name == profile.name) {
+            Some(rule) => {
+                // value of conflicted profile can not be checked, the conflict is reported instead
+                let found = profile.value.get_type();
+                if rule.value_types.len() == 1 && found != rule.value_types[0] {
+                    errors.push(ProfileError::TypeUnmatch { name: profile.name.clone(), expected: rule.value_types[0], found });
+                }
+            },
+            None => errors.push(ProfileError::Unknown { name: profile.name.clone() })
+        }
+    }
+    for rule in &rules {
+        if rule.value_types.len() > 1 {
+            errors.push(ProfileError::Conflict { name: rule.name.clone(), types: rule.value_types.clone() });
+        }
+        let count = user_profiles.iter().filter(|p| p.name == rule.name).count();
+        if rule.required && count == 0 {
+            errors.push(ProfileError::Missing { name: rule.name.clone() });
+        }
+        if !rule.multiple && count > 1 {
+            errors.push(ProfileError::Multiple { name: rule.name.clone() });
+        }
+    }
+    errors
+}
+
+pub(crate) async fn list_user_role_profile(auth: &Auth, user_id: Uuid)
+    -> Result<Vec<RoleProfileSchema>, Status>
+{
+    let mut role_profiles = Vec::new();
+    for role in auth.list_role_by_user(user_id).await? {
+        role_profiles.extend(auth.list_role_profile_by_role(role.id).await?);
+    }
+    Ok(role_profiles)
+}
+
+pub(crate) async fn validate_user_profile(auth: &Auth, user_id: Uuid, user_profiles: &[UserProfileSchema])
+    -> Result<Vec<ProfileError>, Status>
+{
+    let role_profiles = list_user_role_profile(auth, user_id).await?;
+    Ok(validate_profiles(&role_profiles, user_profiles))
+}
+
+// check the stored user profiles after the change, missing required value is allowed since profiles are added one by one
+pub(crate) async fn validate_user_profile_change(auth: &Auth, user_id: Uuid, change: UserProfileSchema)
+    -> Result<Vec<ProfileError>, Status>
+{
+    let mut user_profiles = auth.list_user_profile_by_user(user_id).await?;
+    match user_profiles.iter_mut().find(|p| p.id == change.id && change.id != 0) {
+        Some(profile) => *profile = change,
+        None => user_profiles.push(change)
+    }
+    let errors = validate_user_profile(auth, user_id, &user_profiles).await?;
+    Ok(errors.into_iter().filter(|e| !matches!(e, ProfileError::Missing { .. })).collect())
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, dangerous::insecure_decode};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::error::Error;
+
+#[derive(Debug, Deserialize)]
+struct TokenClaims {
+    sub: String,
+    iat: i64,
+    exp: i64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessClaims {
+    pub user_id: Uuid,
+    pub issued_at: DateTime<Utc>,
+    pub expire: DateTime<Utc>
+}
+
+impl AccessClaims {
+
+    pub fn decode(access_token: &str) -> Result<AccessClaims, Error> {
+        let token_data = insecure_decode::<TokenClaims>(access_token)
+            .map_err(|e| Error::InvalidToken(e.to_string()))?;
+        token_data.claims.try_into()
+    }
+
+    pub fn verify(access_token: &str, access_key: &[u8]) -> Result<AccessClaims, Error> {
+        let key = DecodingKey::from_secret(access_key);
+        let validation = Validation::new(Algorithm::HS256);
+        let token_data = decode::<TokenClaims>(access_token, &key, &validation)
+            .map_err(|e| Error::InvalidToken(e.to_string()))?;
+        token_data.claims.try_into()
+    }
+
+    pub fn time_to_expire(&self) -> Duration {
+        self.expire - Utc::now()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expire <= Utc::now()
+    }
+
+    pub fn expire_within(&self, duration: Duration) -> bool {
+        self.time_to_expire() <= duration
+    }
+
+}
+
+impl TryFrom<TokenClaims> for AccessClaims {
+    type Error = Error;
+    fn try_from(value: TokenClaims) -> Result<Self, Self::Error> {
+        let user_id = Uuid::parse_str(&value.sub)
+            .map_err(|e| Error::InvalidToken(e.to_string()))?;
+        let issued_at = DateTime::from_timestamp(value.iat, 0)
+            .ok_or(Error::InvalidToken(String::from("invalid issued-at timestamp")))?;
+        let expire = DateTime::from_timestamp(value.exp, 0)
+            .ok_or(Error::InvalidToken(String::from("invalid expiry timestamp")))?;
+        Ok(AccessClaims { user_id, issued_at, expire })
+    }
+}
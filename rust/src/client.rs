@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tonic::transport::Channel;
+use uuid::Uuid;
+use crate::auth::Auth;
+use crate::resource::Resource;
+use crate::config::ClientConfig;
+use crate::settings::{Settings, SERVER_ADDRESS, SERVER_ADDRESS_AUTH, SERVER_ADDRESS_RESOURCE};
+use crate::session::{Session, SessionApi, tls_address};
+use crate::error::Error;
+
+const LOGIN_REQUIRED: &str = "client is not logged in";
+const RESOURCE_NOT_CONFIGURED: &str = "resource server address is not configured";
+
+#[derive(Debug)]
+pub struct RmcsClient {
+    auth: Auth,
+    auth_config: ClientConfig,
+    resource_config: Option<ClientConfig>,
+    channels: Arc<Mutex<HashMap<String, Channel>>>,
+    session: Option<Session>
+}
+
+impl RmcsClient {
+
+    // auth and resource services served on the same endpoint
+    pub async fn connect(config: ClientConfig)
+        -> Result<Self, Error>
+    {
+        Self::connect_with_resource(config.clone(), Some(config)).await
+    }
+
+    // resource config set connection options of resource servers, its address is only used for api without address
+    pub async fn connect_with_resource(auth_config: ClientConfig, resource_config: Option<ClientConfig>)
+        -> Result<Self, Error>
+    {
+        let channel = auth_config.channel().await?;
+        let mut channels = HashMap::new();
+        channels.insert(tls_address(&auth_config.address, auth_config.is_tls()), channel.clone());
+        Ok(RmcsClient {
+            auth: auth_config.apply_auth(Auth::new_with_channel(channel)),
+            auth_config,
+            resource_config,
+            channels: Arc::new(Mutex::new(channels)),
+            session: None
+        })
+    }
+
+    // SERVER_ADDRESS is used for service which has no specific address key
+    pub async fn from_env()
+        -> Result<Self, Error>
+    {
+        let settings = Settings::from_env()?;
+        let config = |key: &str| match settings.get(key) {
+            Some(_) => settings.client_config(key).map(Some),
+            None => Ok(None)
+        };
+        let shared = config(SERVER_ADDRESS)?;
+        let auth_config = config(SERVER_ADDRESS_AUTH)?.or(shared.clone())
+            .ok_or(Error::Config(format!("missing configuration key {} or {}", SERVER_ADDRESS_AUTH, SERVER_ADDRESS)))?;
+        let resource_config = config(SERVER_ADDRESS_RESOURCE)?.or(shared);
+        Self::connect_with_resource(auth_config, resource_config).await
+    }
+
+    // channel is shared by all clients connecting to the same address,
+    // api address use https when the connection options have TLS
+    fn channel(&self, address: &str) -> Result<Channel, Error> {
+        let base = self.resource_config.as_ref().unwrap_or(&self.auth_config);
+        let address = match (address.is_empty(), &self.resource_config) {
+            (false, _) => tls_address(address, base.is_tls()),
+            (true, Some(config)) => tls_address(&config.address, base.is_tls()),
+            (true, None) => return Err(Error::Config(RESOURCE_NOT_CONFIGURED.to_owned()))
+        };
+        let config = ClientConfig { address: address.clone(), ..base.clone() };
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(channel) = channels.get(&address) {
+            return Ok(channel.clone());
+        }
+        let channel = config.endpoint()?.connect_lazy();
+        channels.insert(address, channel.clone());
+        Ok(channel)
+    }
+
+    fn new_resource(&self, address: &str) -> Result<Resource, Error> {
+        let config = self.resource_config.as_ref().unwrap_or(&self.auth_config);
+        Ok(config.apply_resource(Resource::new_with_channel(self.channel(address)?)))
+    }
+
+    pub async fn login(&mut self, username: &str, password: &str)
+        -> Result<(), Error>
+    {
+        if self.session.is_some() {
+            self.logout().await?;
+        }
+        let session = Session::login_with(&self.auth, username, password, |api| self.new_resource(&api.address)).await?;
+        self.session = Some(session);
+        Ok(())
+    }
+
+    // session is kept when logout failed so it can be retried
+    pub async fn logout(&mut self)
+        -> Result<(), Error>
+    {
+        let session = match &self.session {
+            Some(session) => session,
+            None => return Ok(())
+        };
+        session.auth().user_logout(session.user_id(), session.auth_token()).await?;
+        self.session = None;
+        Ok(())
+    }
+
+    pub fn is_logged_in(&self) -> bool {
+        self.session.is_some()
+    }
+
+    pub fn user_id(&self) -> Option<Uuid> {
+        self.session.as_ref().map(|s| s.user_id())
+    }
+
+    // auth client carry the auth token of the current session
+    pub fn auth(&self) -> Auth {
+        match &self.session {
+            Some(session) => session.auth(),
+            None => self.auth.clone()
+        }
+    }
+
+    pub fn apis(&self) -> &[SessionApi] {
+        self.session.as_ref().map(|s| s.apis()).unwrap_or_default()
+    }
+
+    pub fn resource(&self, api: &str)
+        -> Result<Resource, Error>
+    {
+        let session = self.session.as_ref().ok_or(Error::Unauthenticated(LOGIN_REQUIRED.to_owned()))?;
+        session.resource_by_name(api).ok_or(Error::NotFound(format!("api {} not found", api)))
+    }
+
+    pub fn resource_by_id(&self, api_id: Uuid)
+        -> Result<Resource, Error>
+    {
+        let session = self.session.as_ref().ok_or(Error::Unauthenticated(LOGIN_REQUIRED.to_owned()))?;
+        session.resource(api_id).ok_or(Error::NotFound(format!("api {} not found", api_id)))
+    }
+
+    // resource client without token, for procedures that do not require login
+    pub fn resource_public(&self)
+        -> Result<Resource, Error>
+    {
+        let config = self.resource_config.as_ref().ok_or(Error::Config(RESOURCE_NOT_CONFIGURED.to_owned()))?;
+        self.new_resource(&config.address)
+    }
+
+}
@@ -0,0 +1,178 @@
+use std::path::Path;
+use std::time::Duration;
+use tonic::transport::{Channel, Endpoint, ClientTlsConfig, Certificate, Identity};
+use crate::auth::Auth;
+use crate::resource::Resource;
+use crate::error::Error;
+use crate::retry::RetryPolicy;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientConfig {
+    pub address: String,
+    pub ca_certificates: Vec<Vec<u8>>,
+    pub client_certificate: Option<Vec<u8>>,
+    pub client_key: Option<Vec<u8>>,
+    pub domain: Option<String>,
+    pub connect_timeout: Option<Duration>,
+    pub timeout: Option<Duration>,
+    pub long_timeout: Option<Duration>,
+    pub keep_alive_interval: Option<Duration>,
+    pub keep_alive_timeout: Option<Duration>,
+    pub keep_alive_while_idle: bool,
+    pub stream_window_size: Option<u32>,
+    pub connection_window_size: Option<u32>,
+    pub plaintext_password: bool,
+    pub retry: RetryPolicy
+}
+
+impl ClientConfig {
+
+    pub fn new(address: &str) -> Self {
+        ClientConfig {
+            address: address.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn ca_certificate(mut self, pem: impl AsRef<[u8]>) -> Self {
+        self.ca_certificates.push(pem.as_ref().to_vec());
+        self
+    }
+
+    pub fn ca_certificate_file(self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let pem = std::fs::read(path)?;
+        Ok(self.ca_certificate(pem))
+    }
+
+    pub fn identity(mut self, certificate_pem: impl AsRef<[u8]>, key_pem: impl AsRef<[u8]>) -> Self {
+        self.client_certificate = Some(certificate_pem.as_ref().to_vec());
+        self.client_key = Some(key_pem.as_ref().to_vec());
+        self
+    }
+
+    pub fn identity_file(self, certificate_path: impl AsRef<Path>, key_path: impl AsRef<Path>) -> Result<Self, Error> {
+        let certificate = std::fs::read(certificate_path)?;
+        let key = std::fs::read(key_path)?;
+        Ok(self.identity(certificate, key))
+    }
+
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_owned());
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn long_timeout(mut self, timeout: Duration) -> Self {
+        self.long_timeout = Some(timeout);
+        self
+    }
+
+    pub fn keep_alive(mut self, interval: Duration, timeout: Duration, while_idle: bool) -> Self {
+        self.keep_alive_interval = Some(interval);
+        self.keep_alive_timeout = Some(timeout);
+        self.keep_alive_while_idle = while_idle;
+        self
+    }
+
+    pub fn window_size(mut self, stream: u32, connection: u32) -> Self {
+        self.stream_window_size = Some(stream);
+        self.connection_window_size = Some(connection);
+        self
+    }
+
+    // allow sending user and api password over non TLS channel
+    pub fn plaintext_password(mut self, allow: bool) -> Self {
+        self.plaintext_password = allow;
+        self
+    }
+
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    pub fn is_tls(&self) -> bool {
+        self.address.starts_with("https://")
+            || !self.ca_certificates.is_empty()
+            || self.client_certificate.is_some()
+            || self.domain.is_some()
+    }
+
+    fn tls_config(&self) -> Result<ClientTlsConfig, Error> {
+        let mut tls = ClientTlsConfig::new()
+            .ca_certificates(self.ca_certificates.iter().map(Certificate::from_pem));
+        match (&self.client_certificate, &self.client_key) {
+            (Some(certificate), Some(key)) => tls = tls.identity(Identity::from_pem(certificate, key)),
+            (None, None) => {},
+            _ => return Err(Error::InvalidArgument("client certificate and key must be set together".to_owned()))
+        }
+        if let Some(domain) = &self.domain {
+            tls = tls.domain_name(domain);
+        }
+        Ok(tls)
+    }
+
+    pub fn endpoint(&self) -> Result<Endpoint, Error> {
+        let mut endpoint = Endpoint::from_shared(self.address.clone())
+            .map_err(|_| Error::InvalidAddress(self.address.clone()))?;
+        if self.is_tls() {
+            endpoint = endpoint.tls_config(self.tls_config()?)?;
+        }
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(interval) = self.keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        Ok(endpoint
+            .keep_alive_while_idle(self.keep_alive_while_idle)
+            .initial_stream_window_size(self.stream_window_size)
+            .initial_connection_window_size(self.connection_window_size))
+    }
+
+    pub async fn channel(&self) -> Result<Channel, Error> {
+        Ok(self.endpoint()?.connect().await?)
+    }
+
+    // timeout not set in config keep the default timeout of auth and resource
+    pub(crate) fn apply_auth(&self, auth: Auth) -> Auth {
+        let auth = auth.with_retry(self.retry.clone())
+            .with_plaintext_password(self.is_tls() || self.plaintext_password);
+        match self.timeout {
+            Some(_) => auth.with_timeout(self.timeout),
+            None => auth
+        }
+    }
+
+    pub(crate) fn apply_resource(&self, resource: Resource) -> Resource {
+        let mut resource = resource.with_retry(self.retry.clone());
+        if self.timeout.is_some() {
+            resource = resource.with_timeout(self.timeout);
+        }
+        if self.long_timeout.is_some() {
+            resource = resource.with_long_timeout(self.long_timeout);
+        }
+        resource
+    }
+
+    pub async fn auth(&self) -> Result<Auth, Error> {
+        Ok(self.apply_auth(Auth::new_with_channel(self.channel().await?)))
+    }
+
+    pub async fn resource(&self) -> Result<Resource, Error> {
+        Ok(self.apply_resource(Resource::new_with_channel(self.channel().await?)))
+    }
+
+}
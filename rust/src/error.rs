@@ -0,0 +1,109 @@
+use std::fmt;
+use tonic::{Code, Status};
+use crate::resource::data::EMPTY_LENGTH_UNMATCH;
+use crate::auth::ProfileError;
+
+#[derive(Debug)]
+pub enum Error {
+    Transport(tonic::transport::Error),
+    InvalidAddress(String),
+    NotFound(String),
+    Unauthenticated(String),
+    PermissionDenied(String),
+    InvalidArgument(String),
+    InvalidToken(String),
+    Parse(String),
+    Io(std::io::Error),
+    Config(String),
+    Timeout(String),
+    Profile(Vec<ProfileError>),
+    LengthUnmatch,
+    Status(Status)
+}
+
+impl Error {
+
+    pub fn code(&self) -> Code {
+        match self {
+            Error::Transport(_) => Code::Unavailable,
+            Error::InvalidAddress(_) => Code::InvalidArgument,
+            Error::NotFound(_) => Code::NotFound,
+            Error::Unauthenticated(_) => Code::Unauthenticated,
+            Error::PermissionDenied(_) => Code::PermissionDenied,
+            Error::InvalidArgument(_) => Code::InvalidArgument,
+            Error::InvalidToken(_) => Code::Unauthenticated,
+            Error::Parse(_) => Code::InvalidArgument,
+            Error::Io(_) => Code::Internal,
+            Error::Config(_) => Code::FailedPrecondition,
+            Error::Timeout(_) => Code::DeadlineExceeded,
+            Error::Profile(_) => Code::InvalidArgument,
+            Error::LengthUnmatch => Code::InvalidArgument,
+            Error::Status(status) => status.code()
+        }
+    }
+
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(error) => write!(f, "transport error: {}", error),
+            Error::InvalidAddress(addr) => write!(f, "invalid address: {}", addr),
+            Error::NotFound(message) => write!(f, "not found: {}", message),
+            Error::Unauthenticated(message) => write!(f, "unauthenticated: {}", message),
+            Error::PermissionDenied(message) => write!(f, "permission denied: {}", message),
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+            Error::InvalidToken(message) => write!(f, "invalid token: {}", message),
+            Error::Parse(message) => write!(f, "parse error: {}", message),
+            Error::Io(error) => write!(f, "io error: {}", error),
+            Error::Config(message) => write!(f, "configuration error: {}", message),
+            Error::Timeout(message) => write!(f, "timeout: {}", message),
+            Error::Profile(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "invalid profile: {}", messages.join(", "))
+            },
+            Error::LengthUnmatch => write!(f, "invalid argument: {}", EMPTY_LENGTH_UNMATCH),
+            Error::Status(status) => write!(f, "{}: {}", status.code(), status.message())
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::Status(status) => Some(status),
+            _ => None
+        }
+    }
+}
+
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        let message = status.message().to_owned();
+        match status.code() {
+            Code::NotFound => Error::NotFound(message),
+            Code::Unauthenticated => Error::Unauthenticated(message),
+            Code::PermissionDenied => Error::PermissionDenied(message),
+            Code::DeadlineExceeded => Error::Timeout(message),
+            // timeout set on the channel endpoint is reported by tonic as cancelled
+            Code::Cancelled if message.contains("Timeout expired") => Error::Timeout(message),
+            Code::InvalidArgument if message == EMPTY_LENGTH_UNMATCH => Error::LengthUnmatch,
+            Code::InvalidArgument => Error::InvalidArgument(message),
+            _ => Error::Status(status)
+        }
+    }
+}
+
+impl From<tonic::transport::Error> for Error {
+    fn from(error: tonic::transport::Error) -> Self {
+        Error::Transport(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
@@ -1,5 +1,25 @@
 pub mod auth;
 pub mod resource;
+pub mod error;
+pub mod options;
+pub mod config;
+pub mod settings;
+pub mod retry;
+pub mod session;
+pub mod registry;
+pub mod client;
+pub mod claims;
+pub mod provision;
+pub mod snapshot;
 
 pub use auth::Auth;
 pub use resource::Resource;
+pub use error::Error;
+pub use options::CallOptions;
+pub use config::ClientConfig;
+pub use settings::Settings;
+pub use retry::RetryPolicy;
+pub use session::Session;
+pub use registry::ApiRegistry;
+pub use client::RmcsClient;
+pub use claims::AccessClaims;
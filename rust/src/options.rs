@@ -0,0 +1,117 @@
+use std::sync::{Arc, RwLock};
+use std::future::Future;
+use std::time::Duration;
+use tonic::{Request, Status};
+use tonic::metadata::{MetadataKey, MetadataValue};
+use tonic::service::Interceptor;
+use rmcs_api_server::utility::interceptor::TokenInterceptor;
+
+const METADATA_INVALID: &str = "invalid request metadata";
+pub(crate) const TIMEOUT_EXPIRED: &str = "request timeout expired";
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+pub const LONG_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CallOptions {
+    pub token: Option<String>,
+    pub timeout: Option<Duration>,
+    pub metadata: Vec<(String, String)>
+}
+
+impl CallOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_owned());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum CallToken {
+    Fixed(Arc<str>),
+    Shared(Arc<RwLock<(String, String)>>)
+}
+
+impl CallToken {
+    fn get(&self) -> String {
+        match self {
+            CallToken::Fixed(token) => token.to_string(),
+            CallToken::Shared(token) => token.read().unwrap_or_else(|e| e.into_inner()).0.clone()
+        }
+    }
+}
+
+tokio::task_local! {
+    static CALL_OPTIONS: Arc<CallOptions>;
+}
+
+// run a call with its options, the options are read by the interceptor of generated clients
+// so the clients are built once and shared by calls with different options
+pub(crate) async fn with_call_options<T, Fut>(options: &Arc<CallOptions>, future: Fut)
+    -> T
+    where Fut: Future<Output = T>
+{
+    CALL_OPTIONS.scope(options.clone(), future).await
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CallInterceptor {
+    token: CallToken
+}
+
+impl CallInterceptor {
+    pub(crate) fn new(token: CallToken) -> Self {
+        CallInterceptor { token }
+    }
+}
+
+impl Interceptor for CallInterceptor {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let options = CALL_OPTIONS.try_with(|options| options.clone()).unwrap_or_default();
+        // token in call options replace the token of client instance
+        let token = match &options.token {
+            Some(token) => token.clone(),
+            None => self.token.get()
+        };
+        let mut request = TokenInterceptor(token).call(request)?;
+        if let Some(timeout) = options.timeout {
+            request.set_timeout(timeout);
+        }
+        for (key, value) in &options.metadata {
+            let key = MetadataKey::from_bytes(key.to_lowercase().as_bytes())
+                .map_err(|_| Status::invalid_argument(METADATA_INVALID))?;
+            let value = MetadataValue::try_from(value.as_str())
+                .map_err(|_| Status::invalid_argument(METADATA_INVALID))?;
+            request.metadata_mut().insert(key, value);
+        }
+        Ok(request)
+    }
+}
+
+pub(crate) async fn deadline<T, Fut>(timeout: Option<Duration>, future: Fut)
+    -> Result<T, Status>
+    where Fut: Future<Output = Result<T, Status>>
+{
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, future)
+            .await
+            .unwrap_or(Err(Status::deadline_exceeded(TIMEOUT_EXPIRED))),
+        None => future.await
+    }
+}
@@ -0,0 +1,557 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use serde::{Serialize, Deserialize};
+use tonic::{Code, Status};
+use uuid::Uuid;
+use crate::auth::{Auth, ApiSchema, RoleSchema, UserSchema, RoleProfileSchema, ProfileMode, DataType};
+use crate::auth::utility::generate_access_key;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub apis: Vec<ApiManifest>,
+    #[serde(default)]
+    pub users: Vec<UserManifest>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiManifest {
+    pub name: String,
+    pub address: String,
+    pub category: String,
+    #[serde(default)]
+    pub description: String,
+    pub password: String,
+    #[serde(default)]
+    pub procedures: Vec<ProcedureManifest>,
+    #[serde(default)]
+    pub roles: Vec<RoleManifest>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcedureManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleManifest {
+    pub name: String,
+    #[serde(default)]
+    pub multi: bool,
+    #[serde(default)]
+    pub ip_lock: bool,
+    pub access_duration: i32,
+    pub refresh_duration: i32,
+    #[serde(default)]
+    pub procedures: Vec<String>,
+    #[serde(default)]
+    pub profiles: Vec<RoleProfileManifest>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleProfileManifest {
+    pub name: String,
+    pub value_type: String,
+    pub mode: String
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserManifest {
+    pub name: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+    pub password: String,
+    #[serde(default)]
+    pub roles: Vec<UserRoleManifest>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserRoleManifest {
+    pub api: String,
+    pub role: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    CreateApi(ApiManifest),
+    UpdateApi(ApiManifest),
+    CreateProcedure { api: String, procedure: ProcedureManifest },
+    UpdateProcedure { api: String, procedure: ProcedureManifest },
+    CreateRole { api: String, role: RoleManifest },
+    UpdateRole { api: String, role: RoleManifest },
+    AddRoleAccess { api: String, role: String, procedure: String },
+    CreateRoleProfile { api: String, role: String, profile: RoleProfileManifest },
+    UpdateRoleProfile { api: String, role: String, profile: RoleProfileManifest },
+    CreateUser(UserManifest),
+    UpdateUser(UserManifest),
+    AddUserRole { user: String, api: String, role: String },
+    RemoveUserRole { user: String, api: String, role: String },
+    RemoveRoleAccess { api: String, role: String, procedure: String },
+    DeleteRole { api: String, role: String },
+    DeleteProcedure { api: String, procedure: String }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<Change>
+}
+
+// prune revoke procedures, roles, role accesses and user roles of declared apis and users which are not in the manifest
+#[derive(Debug, Clone, Default)]
+pub struct PlanOptions {
+    pub prune: bool
+}
+
+impl Manifest {
+
+    pub fn from_toml(content: &str) -> Result<Manifest, Error> {
+        toml::from_str(content).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    pub fn from_json(content: &str) -> Result<Manifest, Error> {
+        serde_json::from_str(content).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Manifest, Error> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json(&content),
+            _ => Self::from_toml(&content)
+        }
+    }
+
+    pub async fn plan(&self, auth: &Auth) -> Result<Plan, Error> {
+        self.plan_with(auth, &PlanOptions::default()).await
+    }
+
+    // removals are planned after all other changes, user roles first then role accesses, roles and procedures
+    pub async fn plan_with(&self, auth: &Auth, options: &PlanOptions) -> Result<Plan, Error> {
+        let mut changes = Vec::new();
+        let mut api_removals = Vec::new();
+        let mut user_removals = Vec::new();
+        // keep api id of existing apis for comparing user roles
+        let mut api_ids: HashMap<String, Uuid> = HashMap::new();
+        for api in &self.apis {
+            let api_live = found(auth.read_api_by_name(&api.name).await)?;
+            match api_live {
+                Some(api_live) => {
+                    api_ids.insert(api.name.clone(), api_live.id);
+                    let roles_live = auth.list_role_by_api(api_live.id).await?;
+                    plan_api(auth, api, &api_live, &roles_live, &mut changes).await?;
+                    if options.prune {
+                        plan_api_prune(api, &api_live, &roles_live, &mut api_removals);
+                    }
+                },
+                None => {
+                    changes.push(Change::CreateApi(api.clone()));
+                    plan_api_new(api, &mut changes)?;
+                }
+            }
+        }
+        for user in &self.users {
+            let user_live = found(auth.read_user_by_name(&user.name).await)?;
+            match user_live {
+                Some(user_live) => {
+                    plan_user(auth, user, &user_live, &mut api_ids, &mut changes).await?;
+                    if options.prune {
+                        plan_user_prune(auth, user, &user_live, &api_ids, &mut user_removals).await?;
+                    }
+                },
+                None => {
+                    changes.push(Change::CreateUser(user.clone()));
+                    for user_role in &user.roles {
+                        changes.push(Change::AddUserRole { user: user.name.clone(), api: user_role.api.clone(), role: user_role.role.clone() });
+                    }
+                }
+            }
+        }
+        changes.extend(user_removals);
+        changes.extend(api_removals);
+        Ok(Plan { changes })
+    }
+
+    pub async fn apply(&self, auth: &Auth) -> Result<Plan, Error> {
+        self.apply_with(auth, &PlanOptions::default()).await
+    }
+
+    pub async fn apply_with(&self, auth: &Auth, options: &PlanOptions) -> Result<Plan, Error> {
+        let plan = self.plan_with(auth, options).await?;
+        plan.apply(auth).await?;
+        Ok(plan)
+    }
+
+}
+
+async fn plan_api(auth: &Auth, api: &ApiManifest, api_live: &ApiSchema, roles_live: &[RoleSchema], changes: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    if api.address != api_live.address || api.category != api_live.category || api.description != api_live.description {
+        changes.push(Change::UpdateApi(api.clone()));
+    }
+    for procedure in &api.procedures {
+        match api_live.procedures.iter().find(|p| p.name == procedure.name) {
+            Some(procedure_live) => {
+                if procedure.description != procedure_live.description {
+                    changes.push(Change::UpdateProcedure { api: api.name.clone(), procedure: procedure.clone() });
+                }
+            },
+            None => changes.push(Change::CreateProcedure { api: api.name.clone(), procedure: procedure.clone() })
+        }
+    }
+    for role in &api.roles {
+        let role_live = match roles_live.iter().find(|r| r.name == role.name) {
+            Some(value) => value,
+            None => {
+                plan_role_new(&api.name, role, changes)?;
+                continue;
+            }
+        };
+        if role.multi != role_live.multi || role.ip_lock != role_live.ip_lock
+            || role.access_duration != role_live.access_duration || role.refresh_duration != role_live.refresh_duration
+        {
+            changes.push(Change::UpdateRole { api: api.name.clone(), role: role.clone() });
+        }
+        for procedure in &role.procedures {
+            let linked = api_live.procedures.iter()
+                .find(|p| &p.name == procedure)
+                .map(|p| role_live.procedures.contains(&p.id))
+                .unwrap_or(false);
+            if !linked {
+                changes.push(Change::AddRoleAccess { api: api.name.clone(), role: role.name.clone(), procedure: procedure.clone() });
+            }
+        }
+        let profiles_live = auth.list_role_profile_by_role(role_live.id).await?;
+        plan_role_profile(&api.name, role, &profiles_live, changes)?;
+    }
+    Ok(())
+}
+
+fn plan_api_prune(api: &ApiManifest, api_live: &ApiSchema, roles_live: &[RoleSchema], removals: &mut Vec<Change>) {
+    for role_live in roles_live {
+        let role = match api.roles.iter().find(|r| r.name == role_live.name) {
+            Some(value) => value,
+            None => {
+                removals.push(Change::DeleteRole { api: api.name.clone(), role: role_live.name.clone() });
+                continue;
+            }
+        };
+        for procedure_live in api_live.procedures.iter().filter(|p| role_live.procedures.contains(&p.id)) {
+            if !role.procedures.contains(&procedure_live.name) {
+                removals.push(Change::RemoveRoleAccess { api: api.name.clone(), role: role.name.clone(), procedure: procedure_live.name.clone() });
+            }
+        }
+    }
+    for procedure_live in &api_live.procedures {
+        if !api.procedures.iter().any(|p| p.name == procedure_live.name) {
+            removals.push(Change::DeleteProcedure { api: api.name.clone(), procedure: procedure_live.name.clone() });
+        }
+    }
+}
+
+fn plan_api_new(api: &ApiManifest, changes: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    for procedure in &api.procedures {
+        changes.push(Change::CreateProcedure { api: api.name.clone(), procedure: procedure.clone() });
+    }
+    for role in &api.roles {
+        plan_role_new(&api.name, role, changes)?;
+    }
+    Ok(())
+}
+
+fn plan_role_new(api: &str, role: &RoleManifest, changes: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    changes.push(Change::CreateRole { api: api.to_owned(), role: role.clone() });
+    for procedure in &role.procedures {
+        changes.push(Change::AddRoleAccess { api: api.to_owned(), role: role.name.clone(), procedure: procedure.clone() });
+    }
+    plan_role_profile(api, role, &[], changes)
+}
+
+fn plan_role_profile(api: &str, role: &RoleManifest, profiles_live: &[RoleProfileSchema], changes: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    for profile in &role.profiles {
+        let value_type = parse_data_type(&profile.value_type)?;
+        let mode = parse_profile_mode(&profile.mode)?;
+        match profiles_live.iter().find(|p| p.name == profile.name) {
+            Some(profile_live) => {
+                if value_type != profile_live.value_type || mode != profile_live.mode {
+                    changes.push(Change::UpdateRoleProfile { api: api.to_owned(), role: role.name.clone(), profile: profile.clone() });
+                }
+            },
+            None => changes.push(Change::CreateRoleProfile { api: api.to_owned(), role: role.name.clone(), profile: profile.clone() })
+        }
+    }
+    Ok(())
+}
+
+async fn plan_user(auth: &Auth, user: &UserManifest, user_live: &UserSchema, api_ids: &mut HashMap<String, Uuid>, changes: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    // password stored as hash in the server so it only set when creating user
+    if user.email != user_live.email || user.phone != user_live.phone {
+        changes.push(Change::UpdateUser(user.clone()));
+    }
+    for user_role in &user.roles {
+        // role may belong to an existing api which is not declared in the manifest
+        if !api_ids.contains_key(&user_role.api) && let Some(api_live) = found(auth.read_api_by_name(&user_role.api).await)? {
+            api_ids.insert(user_role.api.clone(), api_live.id);
+        }
+        let linked = match api_ids.get(&user_role.api) {
+            Some(api_id) => user_live.roles.iter().any(|r| &r.api_id == api_id && r.role == user_role.role),
+            None => false
+        };
+        if !linked {
+            changes.push(Change::AddUserRole { user: user.name.clone(), api: user_role.api.clone(), role: user_role.role.clone() });
+        }
+    }
+    Ok(())
+}
+
+async fn plan_user_prune(auth: &Auth, user: &UserManifest, user_live: &UserSchema, api_ids: &HashMap<String, Uuid>, removals: &mut Vec<Change>)
+    -> Result<(), Error>
+{
+    for role_live in &user_live.roles {
+        let api = match api_ids.iter().find(|(_, id)| **id == role_live.api_id) {
+            Some((name, _)) => name.clone(),
+            None => auth.read_api(role_live.api_id).await?.name
+        };
+        if !user.roles.iter().any(|r| r.api == api && r.role == role_live.role) {
+            removals.push(Change::RemoveUserRole { user: user.name.clone(), api, role: role_live.role.clone() });
+        }
+    }
+    Ok(())
+}
+
+impl Plan {
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub async fn apply(&self, auth: &Auth) -> Result<(), Error> {
+        let mut ids = IdCache::default();
+        for change in &self.changes {
+            match change {
+                Change::CreateApi(api) => {
+                    let id = auth.create_api(Uuid::new_v4(), &api.name, &api.address, &api.category, &api.description, &api.password, &generate_access_key()).await?;
+                    ids.apis.insert(api.name.clone(), id);
+                },
+                Change::UpdateApi(api) => {
+                    let id = ids.api(auth, &api.name).await?;
+                    auth.update_api(id, None, Some(&api.address), Some(&api.category), Some(&api.description), None, None).await?;
+                },
+                Change::CreateProcedure { api, procedure } => {
+                    let api_id = ids.api(auth, api).await?;
+                    let id = auth.create_procedure(Uuid::new_v4(), api_id, &procedure.name, &procedure.description).await?;
+                    ids.procedures.insert((api.clone(), procedure.name.clone()), id);
+                },
+                Change::UpdateProcedure { api, procedure } => {
+                    let id = ids.procedure(auth, api, &procedure.name).await?;
+                    auth.update_procedure(id, None, Some(&procedure.description)).await?;
+                },
+                Change::CreateRole { api, role } => {
+                    let api_id = ids.api(auth, api).await?;
+                    let id = auth.create_role(Uuid::new_v4(), api_id, &role.name, role.multi, role.ip_lock, role.access_duration, role.refresh_duration).await?;
+                    ids.roles.insert((api.clone(), role.name.clone()), id);
+                },
+                Change::UpdateRole { api, role } => {
+                    let id = ids.role(auth, api, &role.name).await?;
+                    auth.update_role(id, None, Some(role.multi), Some(role.ip_lock), Some(role.access_duration), Some(role.refresh_duration)).await?;
+                },
+                Change::AddRoleAccess { api, role, procedure } => {
+                    let role_id = ids.role(auth, api, role).await?;
+                    let procedure_id = ids.procedure(auth, api, procedure).await?;
+                    auth.add_role_access(role_id, procedure_id).await?;
+                },
+                Change::CreateRoleProfile { api, role, profile } => {
+                    let role_id = ids.role(auth, api, role).await?;
+                    let value_type = parse_data_type(&profile.value_type)?;
+                    let mode = parse_profile_mode(&profile.mode)?;
+                    auth.create_role_profile(role_id, &profile.name, value_type, mode).await?;
+                },
+                Change::UpdateRoleProfile { api, role, profile } => {
+                    let role_id = ids.role(auth, api, role).await?;
+                    let profile_live = auth.list_role_profile_by_role(role_id).await?
+                        .into_iter()
+                        .find(|p| p.name == profile.name)
+                        .ok_or(Error::NotFound(profile.name.clone()))?;
+                    let value_type = parse_data_type(&profile.value_type)?;
+                    let mode = parse_profile_mode(&profile.mode)?;
+                    auth.update_role_profile(profile_live.id, None, Some(value_type), Some(mode)).await?;
+                },
+                Change::CreateUser(user) => {
+                    let id = auth.create_user(Uuid::new_v4(), &user.name, &user.email, &user.phone, &user.password).await?;
+                    ids.users.insert(user.name.clone(), id);
+                },
+                Change::UpdateUser(user) => {
+                    let id = ids.user(auth, &user.name).await?;
+                    auth.update_user(id, None, Some(&user.email), Some(&user.phone), None).await?;
+                },
+                Change::AddUserRole { user, api, role } => {
+                    let user_id = ids.user(auth, user).await?;
+                    let role_id = ids.role(auth, api, role).await?;
+                    auth.add_user_role(user_id, role_id).await?;
+                },
+                Change::RemoveUserRole { user, api, role } => {
+                    let user_id = ids.user(auth, user).await?;
+                    let role_id = ids.role(auth, api, role).await?;
+                    auth.remove_user_role(user_id, role_id).await?;
+                },
+                Change::RemoveRoleAccess { api, role, procedure } => {
+                    let role_id = ids.role(auth, api, role).await?;
+                    let procedure_id = ids.procedure(auth, api, procedure).await?;
+                    auth.remove_role_access(role_id, procedure_id).await?;
+                },
+                Change::DeleteRole { api, role } => {
+                    // users and profiles of the role are removed together with the role
+                    let role_id = ids.role(auth, api, role).await?;
+                    auth.delete_role_cascade(role_id, false).await.map_err(|(status, _)| status)?;
+                },
+                Change::DeleteProcedure { api, procedure } => {
+                    let procedure_id = ids.procedure(auth, api, procedure).await?;
+                    auth.delete_procedure(procedure_id).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::CreateApi(api) => write!(f, "+ api {}", api.name),
+            Change::UpdateApi(api) => write!(f, "~ api {}", api.name),
+            Change::CreateProcedure { api, procedure } => write!(f, "+ procedure {}/{}", api, procedure.name),
+            Change::UpdateProcedure { api, procedure } => write!(f, "~ procedure {}/{}", api, procedure.name),
+            Change::CreateRole { api, role } => write!(f, "+ role {}/{}", api, role.name),
+            Change::UpdateRole { api, role } => write!(f, "~ role {}/{}", api, role.name),
+            Change::AddRoleAccess { api, role, procedure } => write!(f, "+ access {}/{} -> {}", api, role, procedure),
+            Change::CreateRoleProfile { api, role, profile } => write!(f, "+ role profile {}/{}/{}", api, role, profile.name),
+            Change::UpdateRoleProfile { api, role, profile } => write!(f, "~ role profile {}/{}/{}", api, role, profile.name),
+            Change::CreateUser(user) => write!(f, "+ user {}", user.name),
+            Change::UpdateUser(user) => write!(f, "~ user {}", user.name),
+            Change::AddUserRole { user, api, role } => write!(f, "+ user role {} -> {}/{}", user, api, role),
+            Change::RemoveUserRole { user, api, role } => write!(f, "- user role {} -> {}/{}", user, api, role),
+            Change::RemoveRoleAccess { api, role, procedure } => write!(f, "- access {}/{} -> {}", api, role, procedure),
+            Change::DeleteRole { api, role } => write!(f, "- role {}/{}", api, role),
+            Change::DeleteProcedure { api, procedure } => write!(f, "- procedure {}/{}", api, procedure)
+        }
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct IdCache {
+    apis: HashMap<String, Uuid>,
+    procedures: HashMap<(String, String), Uuid>,
+    roles: HashMap<(String, String), Uuid>,
+    users: HashMap<String, Uuid>
+}
+
+impl IdCache {
+
+    async fn api(&mut self, auth: &Auth, name: &str) -> Result<Uuid, Error> {
+        if let Some(id) = self.apis.get(name) {
+            return Ok(*id);
+        }
+        let id = auth.read_api_by_name(name).await?.id;
+        self.apis.insert(name.to_owned(), id);
+        Ok(id)
+    }
+
+    async fn procedure(&mut self, auth: &Auth, api: &str, name: &str) -> Result<Uuid, Error> {
+        let key = (api.to_owned(), name.to_owned());
+        if let Some(id) = self.procedures.get(&key) {
+            return Ok(*id);
+        }
+        let api_id = self.api(auth, api).await?;
+        let id = auth.read_procedure_by_name(api_id, name).await?.id;
+        self.procedures.insert(key, id);
+        Ok(id)
+    }
+
+    async fn role(&mut self, auth: &Auth, api: &str, name: &str) -> Result<Uuid, Error> {
+        let key = (api.to_owned(), name.to_owned());
+        if let Some(id) = self.roles.get(&key) {
+            return Ok(*id);
+        }
+        let api_id = self.api(auth, api).await?;
+        let id = auth.read_role_by_name(api_id, name).await?.id;
+        self.roles.insert(key, id);
+        Ok(id)
+    }
+
+    async fn user(&mut self, auth: &Auth, name: &str) -> Result<Uuid, Error> {
+        if let Some(id) = self.users.get(name) {
+            return Ok(*id);
+        }
+        let id = auth.read_user_by_name(name).await?.id;
+        self.users.insert(name.to_owned(), id);
+        Ok(id)
+    }
+
+}
+
+fn found<T>(result: Result<T, Status>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(status) if status.code() == Code::NotFound => Ok(None),
+        Err(status) => Err(status.into())
+    }
+}
+
+pub(crate) fn parse_data_type(name: &str) -> Result<DataType, Error> {
+    let data_type = match name.to_uppercase().as_str() {
+        "I8" | "I8T" => DataType::I8T,
+        "I16" | "I16T" => DataType::I16T,
+        "I32" | "I32T" => DataType::I32T,
+        "I64" | "I64T" => DataType::I64T,
+        "I128" | "I128T" => DataType::I128T,
+        "U8" | "U8T" => DataType::U8T,
+        "U16" | "U16T" => DataType::U16T,
+        "U32" | "U32T" => DataType::U32T,
+        "U64" | "U64T" => DataType::U64T,
+        "U128" | "U128T" => DataType::U128T,
+        "F32" | "F32T" => DataType::F32T,
+        "F64" | "F64T" => DataType::F64T,
+        "BOOL" | "BOOLT" => DataType::BoolT,
+        "CHAR" | "CHART" => DataType::CharT,
+        "STRING" | "STRINGT" => DataType::StringT,
+        "BYTES" | "BYTEST" => DataType::BytesT,
+        "NULL" | "NULLT" => DataType::NullT,
+        _ => return Err(Error::Parse(format!("unknown data type {}", name)))
+    };
+    Ok(data_type)
+}
+
+pub(crate) fn parse_profile_mode(name: &str) -> Result<ProfileMode, Error> {
+    let mode = match name.to_uppercase().replace(['_', '-', ' '], "").as_str() {
+        "SINGLEOPTIONAL" => ProfileMode::SingleOptional,
+        "SINGLEREQUIRED" => ProfileMode::SingleRequired,
+        "MULTIPLEOPTIONAL" => ProfileMode::MultipleOptional,
+        "MULTIPLEREQUIRED" => ProfileMode::MultipleRequired,
+        _ => return Err(Error::Parse(format!("unknown profile mode {}", name)))
+    };
+    Ok(mode)
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tonic::transport::Endpoint;
+use uuid::Uuid;
+use rmcs_auth_api::auth::UserLoginResponse;
+use crate::auth::{Auth, ApiSchema};
+use crate::resource::Resource;
+use crate::error::Error;
+use crate::session::{api_address, parse_id};
+
+#[derive(Debug, Clone)]
+pub struct RegistryApi {
+    pub id: Uuid,
+    pub name: String,
+    pub address: String,
+    pub category: String,
+    pub resource: Resource
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiRegistry {
+    auth: Auth,
+    category: String,
+    tokens: HashMap<Uuid, (String, String)>,
+    apis: Arc<RwLock<Vec<RegistryApi>>>
+}
+
+impl ApiRegistry {
+
+    pub fn new(auth: &Auth, category: &str) -> Self {
+        ApiRegistry {
+            auth: auth.clone(),
+            category: category.to_owned(),
+            tokens: HashMap::new(),
+            apis: Arc::new(RwLock::new(Vec::new()))
+        }
+    }
+
+    // attach access and refresh tokens of every api in the login response
+    pub fn with_login(mut self, login: &UserLoginResponse) -> Result<Self, Error> {
+        self.auth = self.auth.with_token(&login.auth_token);
+        self.tokens = login.access_tokens.iter()
+            .map(|t| Ok((parse_id(&t.api_id)?, (t.access_token.clone(), t.refresh_token.clone()))))
+            .collect::<Result<HashMap<Uuid, (String, String)>, Error>>()?;
+        Ok(self)
+    }
+
+    pub async fn login(auth: &Auth, category: &str, username: &str, password: &str)
+        -> Result<Self, Error>
+    {
+        let login = auth.user_login(username, password).await?;
+        let registry = ApiRegistry::new(auth, category).with_login(&login)?;
+        registry.resolve().await?;
+        Ok(registry)
+    }
+
+    fn connect(&self, api: &ApiSchema, token: Option<(String, String)>) -> Result<Resource, Error> {
+        let endpoint = Endpoint::from_shared(api_address(&api.address))
+            .map_err(|_| Error::InvalidAddress(api.address.clone()))?;
+        let resource = Resource::new_with_channel(endpoint.connect_lazy());
+        Ok(match token {
+            Some((access_token, refresh_token)) => resource
+                .with_token(&access_token, &refresh_token)
+                .with_auth(&self.auth, api.id),
+            None => resource
+        })
+    }
+
+    // read api list from auth server, only api with new or changed address is reconnected
+    pub async fn resolve(&self)
+        -> Result<Vec<RegistryApi>, Error>
+    {
+        let schemas = self.auth.list_api_by_category(&self.category).await?;
+        let current = self.apis();
+        let mut apis = Vec::new();
+        for schema in schemas {
+            let resource = match current.iter().find(|a| a.id == schema.id) {
+                Some(api) if api.address == schema.address => api.resource.clone(),
+                // keep refreshed token of the previous client when address changed
+                Some(api) => self.connect(&schema, Some(api.resource.token()))?,
+                None => self.connect(&schema, self.tokens.get(&schema.id).cloned())?
+            };
+            apis.push(RegistryApi {
+                id: schema.id,
+                name: schema.name,
+                address: schema.address,
+                category: schema.category,
+                resource
+            });
+        }
+        *self.apis.write().unwrap_or_else(|e| e.into_inner()) = apis.clone();
+        Ok(apis)
+    }
+
+    pub fn apis(&self) -> Vec<RegistryApi> {
+        self.apis.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    // resolve api list again when the name is not registered yet
+    pub async fn resource(&self, name: &str)
+        -> Result<Resource, Error>
+    {
+        let find = |apis: &[RegistryApi]| apis.iter().find(|a| a.name == name).map(|a| a.resource.clone());
+        if let Some(resource) = find(&self.apis()) {
+            return Ok(resource);
+        }
+        find(&self.resolve().await?).ok_or(Error::NotFound(format!("api {} not found", name)))
+    }
+
+    pub async fn resource_by_id(&self, id: Uuid)
+        -> Result<Resource, Error>
+    {
+        let find = |apis: &[RegistryApi]| apis.iter().find(|a| a.id == id).map(|a| a.resource.clone());
+        if let Some(resource) = find(&self.apis()) {
+            return Ok(resource);
+        }
+        find(&self.resolve().await?).ok_or(Error::NotFound(format!("api {} not found", id)))
+    }
+
+    // periodically resolve api list so address changes made by update_api are applied
+    pub fn watch(&self, interval: Duration) -> JoinHandle<()> {
+        let registry = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                // failed resolve keep the previous api list until the next tick
+                let _ = registry.resolve().await;
+            }
+        })
+    }
+
+}
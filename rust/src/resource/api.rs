@@ -0,0 +1,2220 @@
+use std::future::Future;
+use tonic::Status;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::{Resource, DataType, DataValue, ModelSchema, ModelConfigSchema, TagSchema, DeviceSchema, DeviceConfigSchema, GatewaySchema, GatewayConfigSchema, TypeSchema,
+    GroupModelSchema, GroupDeviceSchema, GroupGatewaySchema, SetSchema, SetTemplateSchema, DataSchema, DataSetSchema, BufferSchema, BufferSetSchema,
+    SliceSchema, SliceSetSchema, LogSchema};
+
+// resource procedures grouped by service, application code can depend on these traits
+// so the resource client can be replaced by a fake or wrapped by a decorator
+pub trait ModelApi: Send + Sync {
+
+    fn read_model(&self, id: Uuid)
+        -> impl Future<Output = Result<ModelSchema, Status>> + Send;
+
+    fn list_model_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send;
+
+    fn list_model_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send;
+
+    fn list_model_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send;
+
+    fn list_model_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send;
+
+    fn list_model_option(&self, type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send;
+
+    fn create_model(&self, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_model(&self, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_model(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_model_config(&self, id: i32)
+        -> impl Future<Output = Result<ModelConfigSchema, Status>> + Send;
+
+    fn list_model_config_by_model(&self, model_id: Uuid)
+        -> impl Future<Output = Result<Vec<ModelConfigSchema>, Status>> + Send;
+
+    fn create_model_config(&self, model_id: Uuid, index: i32, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_model_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_model_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_tag(&self, model_id: Uuid, tag: i16)
+        -> impl Future<Output = Result<TagSchema, Status>> + Send;
+
+    fn list_tag_by_model(&self, model_id: Uuid)
+        -> impl Future<Output = Result<Vec<TagSchema>, Status>> + Send;
+
+    fn create_tag(&self, model_id: Uuid, tag: i16, name: &str, members: &[i16])
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn update_tag(&self, model_id: Uuid, tag: i16, name: Option<&str>, members: Option<&[i16]>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_tag(&self, model_id: Uuid, tag: i16)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+pub trait DeviceApi: Send + Sync {
+
+    fn read_device(&self, id: Uuid)
+        -> impl Future<Output = Result<DeviceSchema, Status>> + Send;
+
+    fn read_device_by_sn(&self, serial_number: &str)
+        -> impl Future<Output = Result<DeviceSchema, Status>> + Send;
+
+    fn list_device_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send;
+
+    fn list_device_by_gateway(&self, gateway_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send;
+
+    fn list_device_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send;
+
+    fn list_device_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send;
+
+    fn list_device_option(&self, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send;
+
+    fn create_device(&self, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_device(&self, id: Uuid, gateway_id: Option<Uuid>, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_device(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<GatewaySchema, Status>> + Send;
+
+    fn read_gateway_by_sn(&self, serial_number: &str)
+        -> impl Future<Output = Result<GatewaySchema, Status>> + Send;
+
+    fn list_gateway_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send;
+
+    fn list_gateway_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send;
+
+    fn list_gateway_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send;
+
+    fn list_gateway_option(&self, type_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send;
+
+    fn create_gateway(&self, id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_gateway(&self, id: Uuid, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_device_config(&self, id: i32)
+        -> impl Future<Output = Result<DeviceConfigSchema, Status>> + Send;
+
+    fn list_device_config_by_device(&self, device_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceConfigSchema>, Status>> + Send;
+
+    fn create_device_config(&self, device_id: Uuid, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_device_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_device_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_gateway_config(&self, id: i32)
+        -> impl Future<Output = Result<GatewayConfigSchema, Status>> + Send;
+
+    fn list_gateway_config_by_gateway(&self, gateway_id: Uuid)
+        -> impl Future<Output = Result<Vec<GatewayConfigSchema>, Status>> + Send;
+
+    fn create_gateway_config(&self, gateway_id: Uuid, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_gateway_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_gateway_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_type(&self, id: Uuid)
+        -> impl Future<Output = Result<TypeSchema, Status>> + Send;
+
+    fn list_type_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send;
+
+    fn list_type_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send;
+
+    fn list_type_option(&self, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send;
+
+    fn create_type(&self, id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_type(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_type(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_type_model(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_type_model(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+pub trait GroupApi: Send + Sync {
+
+    fn read_group_model(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupModelSchema, Status>> + Send;
+
+    fn list_group_model_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send;
+
+    fn list_group_model_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send;
+
+    fn list_group_model_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send;
+
+    fn list_group_model_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send;
+
+    fn create_group_model(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_group_model(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_group_model(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_group_model_member(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_group_model_member(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_group_device(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupDeviceSchema, Status>> + Send;
+
+    fn list_group_device_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send;
+
+    fn list_group_device_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send;
+
+    fn list_group_device_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send;
+
+    fn list_group_device_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send;
+
+    fn create_group_device(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_group_device(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_group_device(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_group_device_member(&self, id: Uuid, device_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_group_device_member(&self, id: Uuid, device_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_group_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupGatewaySchema, Status>> + Send;
+
+    fn list_group_gateway_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send;
+
+    fn list_group_gateway_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send;
+
+    fn list_group_gateway_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send;
+
+    fn list_group_gateway_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send;
+
+    fn create_group_gateway(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_group_gateway(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_group_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+pub trait SetApi: Send + Sync {
+
+    fn read_set(&self, id: Uuid)
+        -> impl Future<Output = Result<SetSchema, Status>> + Send;
+
+    fn list_set_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send;
+
+    fn list_set_by_template(&self, template_id: Uuid)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send;
+
+    fn list_set_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send;
+
+    fn list_set_option(&self, template_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send;
+
+    fn create_set(&self, id: Uuid, template_id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_set(&self, id: Uuid, template_id: Option<Uuid>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_set(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn swap_set_member(&self, id: Uuid, device_id_1: Uuid, model_id_1: Uuid, device_id_2: Uuid, model_id_2: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_set_template(&self, id: Uuid)
+        -> impl Future<Output = Result<SetTemplateSchema, Status>> + Send;
+
+    fn list_set_template_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send;
+
+    fn list_set_template_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send;
+
+    fn list_set_template_option(&self, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send;
+
+    fn create_set_template(&self, id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send;
+
+    fn update_set_template(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_set_template(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn add_set_template_member(&self, id: Uuid, type_id: Uuid, model_id: Uuid, data_index: &[u8])
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn remove_set_template_member(&self, id: Uuid, index: usize)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn swap_set_template_member(&self, id: Uuid, index_1: usize, index_2: usize)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+pub trait DataApi: Send + Sync {
+
+    fn read_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DataSchema, Status>> + Send;
+
+    fn list_data_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn list_data_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send;
+
+    fn read_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DataSetSchema, Status>> + Send;
+
+    fn list_data_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send;
+
+    fn list_data_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send;
+
+    fn list_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send;
+
+    fn create_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn create_data_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_data_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send;
+
+    fn list_data_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_data_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn read_data_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send;
+
+    fn list_data_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_data_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn count_data(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_data_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+}
+
+pub trait BufferApi: Send + Sync {
+
+    fn read_buffer(&self, id: i32)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn read_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn list_buffer_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn read_buffer_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn read_buffer_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn list_buffer_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn read_buffer_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn read_buffer_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send;
+
+    fn list_buffer_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn list_buffer_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send;
+
+    fn read_buffer_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSetSchema, Status>> + Send;
+
+    fn list_buffer_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send;
+
+    fn list_buffer_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send;
+
+    fn list_buffer_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send;
+
+    fn create_buffer(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn create_buffer_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
+        -> impl Future<Output = Result<Vec<i32>, Status>> + Send;
+
+    fn update_buffer(&self, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn update_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_buffer(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_buffer_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send;
+
+    fn list_buffer_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_timestamp_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_timestamp_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn read_buffer_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send;
+
+    fn list_buffer_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_group_timestamp_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn list_buffer_group_timestamp_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send;
+
+    fn count_buffer(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_buffer_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+    fn count_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send;
+
+}
+
+pub trait SliceApi: Send + Sync {
+
+    fn read_slice(&self, id: i32)
+        -> impl Future<Output = Result<SliceSchema, Status>> + Send;
+
+    fn list_slice_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_option(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn list_slice_group_option(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send;
+
+    fn create_slice(&self, device_id: Uuid, model_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_slice(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_slice(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn read_slice_set(&self, id: i32)
+        -> impl Future<Output = Result<SliceSetSchema, Status>> + Send;
+
+    fn list_slice_set_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn list_slice_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn list_slice_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn list_slice_set_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn list_slice_set_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn list_slice_set_option(&self, set_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send;
+
+    fn create_slice_set(&self, set_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_slice_set(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_slice_set(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+pub trait LogApi: Send + Sync {
+
+    fn read_log(&self, id: i32)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn read_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn list_log_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_by_latest(&self, latest: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn read_log_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn read_log_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn list_log_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_by_time(&self, timestamp: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_by_latest(&self, latest: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn read_log_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn read_log_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send;
+
+    fn list_log_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn list_log_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send;
+
+    fn create_log(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: DataValue, tag: Option<i16>)
+        -> impl Future<Output = Result<i32, Status>> + Send;
+
+    fn update_log(&self, id: i32, value: Option<DataValue>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn update_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: Option<DataValue>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_log(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+    fn delete_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send;
+
+}
+
+// all resource procedures, implemented for every type implementing each api trait
+pub trait ResourceApi: ModelApi + DeviceApi + GroupApi + SetApi + DataApi + BufferApi + SliceApi + LogApi {}
+
+impl<T> ResourceApi for T where T: ModelApi + DeviceApi + GroupApi + SetApi + DataApi + BufferApi + SliceApi + LogApi {}
+
+impl ModelApi for Resource {
+
+    fn read_model(&self, id: Uuid)
+        -> impl Future<Output = Result<ModelSchema, Status>> + Send
+    {
+        Resource::read_model(self, id)
+    }
+
+    fn list_model_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send
+    {
+        Resource::list_model_by_ids(self, ids)
+    }
+
+    fn list_model_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send
+    {
+        Resource::list_model_by_type(self, type_id)
+    }
+
+    fn list_model_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send
+    {
+        Resource::list_model_by_name(self, name)
+    }
+
+    fn list_model_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send
+    {
+        Resource::list_model_by_category(self, category)
+    }
+
+    fn list_model_option(&self, type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<ModelSchema>, Status>> + Send
+    {
+        Resource::list_model_option(self, type_id, name, category)
+    }
+
+    fn create_model(&self, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_model(self, id, data_type, category, name, description)
+    }
+
+    fn update_model(&self, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_model(self, id, data_type, category, name, description)
+    }
+
+    fn delete_model(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_model(self, id)
+    }
+
+    fn read_model_config(&self, id: i32)
+        -> impl Future<Output = Result<ModelConfigSchema, Status>> + Send
+    {
+        Resource::read_model_config(self, id)
+    }
+
+    fn list_model_config_by_model(&self, model_id: Uuid)
+        -> impl Future<Output = Result<Vec<ModelConfigSchema>, Status>> + Send
+    {
+        Resource::list_model_config_by_model(self, model_id)
+    }
+
+    fn create_model_config(&self, model_id: Uuid, index: i32, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_model_config(self, model_id, index, name, value, category)
+    }
+
+    fn update_model_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_model_config(self, id, name, value, category)
+    }
+
+    fn delete_model_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_model_config(self, id)
+    }
+
+    fn read_tag(&self, model_id: Uuid, tag: i16)
+        -> impl Future<Output = Result<TagSchema, Status>> + Send
+    {
+        Resource::read_tag(self, model_id, tag)
+    }
+
+    fn list_tag_by_model(&self, model_id: Uuid)
+        -> impl Future<Output = Result<Vec<TagSchema>, Status>> + Send
+    {
+        Resource::list_tag_by_model(self, model_id)
+    }
+
+    fn create_tag(&self, model_id: Uuid, tag: i16, name: &str, members: &[i16])
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::create_tag(self, model_id, tag, name, members)
+    }
+
+    fn update_tag(&self, model_id: Uuid, tag: i16, name: Option<&str>, members: Option<&[i16]>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_tag(self, model_id, tag, name, members)
+    }
+
+    fn delete_tag(&self, model_id: Uuid, tag: i16)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_tag(self, model_id, tag)
+    }
+
+}
+
+impl DeviceApi for Resource {
+
+    fn read_device(&self, id: Uuid)
+        -> impl Future<Output = Result<DeviceSchema, Status>> + Send
+    {
+        Resource::read_device(self, id)
+    }
+
+    fn read_device_by_sn(&self, serial_number: &str)
+        -> impl Future<Output = Result<DeviceSchema, Status>> + Send
+    {
+        Resource::read_device_by_sn(self, serial_number)
+    }
+
+    fn list_device_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send
+    {
+        Resource::list_device_by_ids(self, ids)
+    }
+
+    fn list_device_by_gateway(&self, gateway_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send
+    {
+        Resource::list_device_by_gateway(self, gateway_id)
+    }
+
+    fn list_device_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send
+    {
+        Resource::list_device_by_type(self, type_id)
+    }
+
+    fn list_device_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send
+    {
+        Resource::list_device_by_name(self, name)
+    }
+
+    fn list_device_option(&self, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<DeviceSchema>, Status>> + Send
+    {
+        Resource::list_device_option(self, gateway_id, type_id, name)
+    }
+
+    fn create_device(&self, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_device(self, id, gateway_id, type_id, serial_number, name, description)
+    }
+
+    fn update_device(&self, id: Uuid, gateway_id: Option<Uuid>, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_device(self, id, gateway_id, type_id, serial_number, name, description)
+    }
+
+    fn delete_device(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_device(self, id)
+    }
+
+    fn read_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<GatewaySchema, Status>> + Send
+    {
+        Resource::read_gateway(self, id)
+    }
+
+    fn read_gateway_by_sn(&self, serial_number: &str)
+        -> impl Future<Output = Result<GatewaySchema, Status>> + Send
+    {
+        Resource::read_gateway_by_sn(self, serial_number)
+    }
+
+    fn list_gateway_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send
+    {
+        Resource::list_gateway_by_ids(self, ids)
+    }
+
+    fn list_gateway_by_type(&self, type_id: Uuid)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send
+    {
+        Resource::list_gateway_by_type(self, type_id)
+    }
+
+    fn list_gateway_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send
+    {
+        Resource::list_gateway_by_name(self, name)
+    }
+
+    fn list_gateway_option(&self, type_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<GatewaySchema>, Status>> + Send
+    {
+        Resource::list_gateway_option(self, type_id, name)
+    }
+
+    fn create_gateway(&self, id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_gateway(self, id, type_id, serial_number, name, description)
+    }
+
+    fn update_gateway(&self, id: Uuid, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_gateway(self, id, type_id, serial_number, name, description)
+    }
+
+    fn delete_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_gateway(self, id)
+    }
+
+    fn read_device_config(&self, id: i32)
+        -> impl Future<Output = Result<DeviceConfigSchema, Status>> + Send
+    {
+        Resource::read_device_config(self, id)
+    }
+
+    fn list_device_config_by_device(&self, device_id: Uuid)
+        -> impl Future<Output = Result<Vec<DeviceConfigSchema>, Status>> + Send
+    {
+        Resource::list_device_config_by_device(self, device_id)
+    }
+
+    fn create_device_config(&self, device_id: Uuid, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_device_config(self, device_id, name, value, category)
+    }
+
+    fn update_device_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_device_config(self, id, name, value, category)
+    }
+
+    fn delete_device_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_device_config(self, id)
+    }
+
+    fn read_gateway_config(&self, id: i32)
+        -> impl Future<Output = Result<GatewayConfigSchema, Status>> + Send
+    {
+        Resource::read_gateway_config(self, id)
+    }
+
+    fn list_gateway_config_by_gateway(&self, gateway_id: Uuid)
+        -> impl Future<Output = Result<Vec<GatewayConfigSchema>, Status>> + Send
+    {
+        Resource::list_gateway_config_by_gateway(self, gateway_id)
+    }
+
+    fn create_gateway_config(&self, gateway_id: Uuid, name: &str, value: DataValue, category: &str)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_gateway_config(self, gateway_id, name, value, category)
+    }
+
+    fn update_gateway_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_gateway_config(self, id, name, value, category)
+    }
+
+    fn delete_gateway_config(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_gateway_config(self, id)
+    }
+
+    fn read_type(&self, id: Uuid)
+        -> impl Future<Output = Result<TypeSchema, Status>> + Send
+    {
+        Resource::read_type(self, id)
+    }
+
+    fn list_type_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send
+    {
+        Resource::list_type_by_ids(self, ids)
+    }
+
+    fn list_type_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send
+    {
+        Resource::list_type_by_name(self, name)
+    }
+
+    fn list_type_option(&self, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<TypeSchema>, Status>> + Send
+    {
+        Resource::list_type_option(self, name)
+    }
+
+    fn create_type(&self, id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_type(self, id, name, description)
+    }
+
+    fn update_type(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_type(self, id, name, description)
+    }
+
+    fn delete_type(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_type(self, id)
+    }
+
+    fn add_type_model(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_type_model(self, id, model_id)
+    }
+
+    fn remove_type_model(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_type_model(self, id, model_id)
+    }
+
+}
+
+impl GroupApi for Resource {
+
+    fn read_group_model(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupModelSchema, Status>> + Send
+    {
+        Resource::read_group_model(self, id)
+    }
+
+    fn list_group_model_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send
+    {
+        Resource::list_group_model_by_ids(self, ids)
+    }
+
+    fn list_group_model_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send
+    {
+        Resource::list_group_model_by_name(self, name)
+    }
+
+    fn list_group_model_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send
+    {
+        Resource::list_group_model_by_category(self, category)
+    }
+
+    fn list_group_model_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupModelSchema>, Status>> + Send
+    {
+        Resource::list_group_model_option(self, name, category)
+    }
+
+    fn create_group_model(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_group_model(self, id, name, category, description)
+    }
+
+    fn update_group_model(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_group_model(self, id, name, category, description)
+    }
+
+    fn delete_group_model(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_group_model(self, id)
+    }
+
+    fn add_group_model_member(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_group_model_member(self, id, model_id)
+    }
+
+    fn remove_group_model_member(&self, id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_group_model_member(self, id, model_id)
+    }
+
+    fn read_group_device(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupDeviceSchema, Status>> + Send
+    {
+        Resource::read_group_device(self, id)
+    }
+
+    fn list_group_device_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send
+    {
+        Resource::list_group_device_by_ids(self, ids)
+    }
+
+    fn list_group_device_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send
+    {
+        Resource::list_group_device_by_name(self, name)
+    }
+
+    fn list_group_device_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send
+    {
+        Resource::list_group_device_by_category(self, category)
+    }
+
+    fn list_group_device_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupDeviceSchema>, Status>> + Send
+    {
+        Resource::list_group_device_option(self, name, category)
+    }
+
+    fn create_group_device(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_group_device(self, id, name, category, description)
+    }
+
+    fn update_group_device(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_group_device(self, id, name, category, description)
+    }
+
+    fn delete_group_device(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_group_device(self, id)
+    }
+
+    fn add_group_device_member(&self, id: Uuid, device_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_group_device_member(self, id, device_id)
+    }
+
+    fn remove_group_device_member(&self, id: Uuid, device_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_group_device_member(self, id, device_id)
+    }
+
+    fn read_group_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<GroupGatewaySchema, Status>> + Send
+    {
+        Resource::read_group_gateway(self, id)
+    }
+
+    fn list_group_gateway_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send
+    {
+        Resource::list_group_gateway_by_ids(self, ids)
+    }
+
+    fn list_group_gateway_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send
+    {
+        Resource::list_group_gateway_by_name(self, name)
+    }
+
+    fn list_group_gateway_by_category(&self, category: &str)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send
+    {
+        Resource::list_group_gateway_by_category(self, category)
+    }
+
+    fn list_group_gateway_option(&self, name: Option<&str>, category: Option<&str>)
+        -> impl Future<Output = Result<Vec<GroupGatewaySchema>, Status>> + Send
+    {
+        Resource::list_group_gateway_option(self, name, category)
+    }
+
+    fn create_group_gateway(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_group_gateway(self, id, name, category, description)
+    }
+
+    fn update_group_gateway(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_group_gateway(self, id, name, category, description)
+    }
+
+    fn delete_group_gateway(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_group_gateway(self, id)
+    }
+
+    fn add_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_group_gateway_member(self, id, gateway_id)
+    }
+
+    fn remove_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_group_gateway_member(self, id, gateway_id)
+    }
+
+}
+
+impl SetApi for Resource {
+
+    fn read_set(&self, id: Uuid)
+        -> impl Future<Output = Result<SetSchema, Status>> + Send
+    {
+        Resource::read_set(self, id)
+    }
+
+    fn list_set_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send
+    {
+        Resource::list_set_by_ids(self, ids)
+    }
+
+    fn list_set_by_template(&self, template_id: Uuid)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send
+    {
+        Resource::list_set_by_template(self, template_id)
+    }
+
+    fn list_set_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send
+    {
+        Resource::list_set_by_name(self, name)
+    }
+
+    fn list_set_option(&self, template_id: Option<Uuid>, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<SetSchema>, Status>> + Send
+    {
+        Resource::list_set_option(self, template_id, name)
+    }
+
+    fn create_set(&self, id: Uuid, template_id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_set(self, id, template_id, name, description)
+    }
+
+    fn update_set(&self, id: Uuid, template_id: Option<Uuid>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_set(self, id, template_id, name, description)
+    }
+
+    fn delete_set(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_set(self, id)
+    }
+
+    fn add_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_set_member(self, id, device_id, model_id, data_index)
+    }
+
+    fn remove_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_set_member(self, id, device_id, model_id)
+    }
+
+    fn swap_set_member(&self, id: Uuid, device_id_1: Uuid, model_id_1: Uuid, device_id_2: Uuid, model_id_2: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::swap_set_member(self, id, device_id_1, model_id_1, device_id_2, model_id_2)
+    }
+
+    fn read_set_template(&self, id: Uuid)
+        -> impl Future<Output = Result<SetTemplateSchema, Status>> + Send
+    {
+        Resource::read_set_template(self, id)
+    }
+
+    fn list_set_template_by_ids(&self, ids: &[Uuid])
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send
+    {
+        Resource::list_set_template_by_ids(self, ids)
+    }
+
+    fn list_set_template_by_name(&self, name: &str)
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send
+    {
+        Resource::list_set_template_by_name(self, name)
+    }
+
+    fn list_set_template_option(&self, name: Option<&str>)
+        -> impl Future<Output = Result<Vec<SetTemplateSchema>, Status>> + Send
+    {
+        Resource::list_set_template_option(self, name)
+    }
+
+    fn create_set_template(&self, id: Uuid, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<Uuid, Status>> + Send
+    {
+        Resource::create_set_template(self, id, name, description)
+    }
+
+    fn update_set_template(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_set_template(self, id, name, description)
+    }
+
+    fn delete_set_template(&self, id: Uuid)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_set_template(self, id)
+    }
+
+    fn add_set_template_member(&self, id: Uuid, type_id: Uuid, model_id: Uuid, data_index: &[u8])
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::add_set_template_member(self, id, type_id, model_id, data_index)
+    }
+
+    fn remove_set_template_member(&self, id: Uuid, index: usize)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::remove_set_template_member(self, id, index)
+    }
+
+    fn swap_set_template_member(&self, id: Uuid, index_1: usize, index_2: usize)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::swap_set_template_member(self, id, index_1, index_2)
+    }
+
+}
+
+impl DataApi for Resource {
+
+    fn read_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DataSchema, Status>> + Send
+    {
+        Resource::read_data(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_data_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_by_time(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn list_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn list_data_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_by_number_before(self, device_id, model_id, before, number, tag)
+    }
+
+    fn list_data_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_by_number_after(self, device_id, model_id, after, number, tag)
+    }
+
+    fn list_data_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_group_by_time(self, device_ids, model_ids, timestamp, tag)
+    }
+
+    fn list_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_group_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn list_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_group_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+    fn list_data_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_group_by_number_before(self, device_ids, model_ids, before, number, tag)
+    }
+
+    fn list_data_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSchema>, Status>> + Send
+    {
+        Resource::list_data_group_by_number_after(self, device_ids, model_ids, after, number, tag)
+    }
+
+    fn read_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DataSetSchema, Status>> + Send
+    {
+        Resource::read_data_set(self, set_id, timestamp, tag)
+    }
+
+    fn list_data_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send
+    {
+        Resource::list_data_set_by_time(self, set_id, timestamp, tag)
+    }
+
+    fn list_data_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send
+    {
+        Resource::list_data_set_by_latest(self, set_id, latest, tag)
+    }
+
+    fn list_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DataSetSchema>, Status>> + Send
+    {
+        Resource::list_data_set_by_range(self, set_id, begin, end, tag)
+    }
+
+    fn create_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::create_data(self, device_id, model_id, timestamp, data, tag)
+    }
+
+    fn create_data_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::create_data_multiple(self, device_ids, model_ids, timestamps, data, tags)
+    }
+
+    fn delete_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_data(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn read_data_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send
+    {
+        Resource::read_data_timestamp(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_data_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_data_timestamp_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn list_data_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_data_timestamp_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn read_data_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send
+    {
+        Resource::read_data_group_timestamp(self, device_ids, model_ids, timestamp, tag)
+    }
+
+    fn list_data_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_data_group_timestamp_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn list_data_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_data_group_timestamp_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+    fn count_data(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data(self, device_id, model_id, tag)
+    }
+
+    fn count_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn count_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn count_data_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data_group(self, device_ids, model_ids, tag)
+    }
+
+    fn count_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data_group_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn count_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_data_group_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+}
+
+impl BufferApi for Resource {
+
+    fn read_buffer(&self, id: i32)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer(self, id)
+    }
+
+    fn read_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer_by_time(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_buffer_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_ids(self, ids)
+    }
+
+    fn list_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_time(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn list_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn list_buffer_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_number_before(self, device_id, model_id, before, number, tag)
+    }
+
+    fn list_buffer_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_by_number_after(self, device_id, model_id, after, number, tag)
+    }
+
+    fn read_buffer_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer_first(self, device_id, model_id, tag)
+    }
+
+    fn read_buffer_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer_last(self, device_id, model_id, tag)
+    }
+
+    fn list_buffer_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_first(self, number, device_id, model_id, tag)
+    }
+
+    fn list_buffer_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_first_offset(self, number, offset, device_id, model_id, tag)
+    }
+
+    fn list_buffer_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_last(self, number, device_id, model_id, tag)
+    }
+
+    fn list_buffer_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_last_offset(self, number, offset, device_id, model_id, tag)
+    }
+
+    fn list_buffer_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_by_time(self, device_ids, model_ids, timestamp, tag)
+    }
+
+    fn list_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn list_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+    fn list_buffer_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_by_number_before(self, device_ids, model_ids, before, number, tag)
+    }
+
+    fn list_buffer_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_by_number_after(self, device_ids, model_ids, after, number, tag)
+    }
+
+    fn read_buffer_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer_group_first(self, device_ids, model_ids, tag)
+    }
+
+    fn read_buffer_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSchema, Status>> + Send
+    {
+        Resource::read_buffer_group_last(self, device_ids, model_ids, tag)
+    }
+
+    fn list_buffer_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_first(self, number, device_ids, model_ids, tag)
+    }
+
+    fn list_buffer_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_first_offset(self, number, offset, device_ids, model_ids, tag)
+    }
+
+    fn list_buffer_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_last(self, number, device_ids, model_ids, tag)
+    }
+
+    fn list_buffer_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSchema>, Status>> + Send
+    {
+        Resource::list_buffer_group_last_offset(self, number, offset, device_ids, model_ids, tag)
+    }
+
+    fn read_buffer_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<BufferSetSchema, Status>> + Send
+    {
+        Resource::read_buffer_set(self, set_id, timestamp, tag)
+    }
+
+    fn list_buffer_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send
+    {
+        Resource::list_buffer_set_by_time(self, set_id, timestamp, tag)
+    }
+
+    fn list_buffer_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send
+    {
+        Resource::list_buffer_set_by_latest(self, set_id, latest, tag)
+    }
+
+    fn list_buffer_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<BufferSetSchema>, Status>> + Send
+    {
+        Resource::list_buffer_set_by_range(self, set_id, begin, end, tag)
+    }
+
+    fn create_buffer(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_buffer(self, device_id, model_id, timestamp, data, tag)
+    }
+
+    fn create_buffer_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
+        -> impl Future<Output = Result<Vec<i32>, Status>> + Send
+    {
+        Resource::create_buffer_multiple(self, device_ids, model_ids, timestamps, data, tags)
+    }
+
+    fn update_buffer(&self, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_buffer(self, id, data, tag)
+    }
+
+    fn update_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_buffer_by_time(self, device_id, model_id, timestamp, data, tag)
+    }
+
+    fn delete_buffer(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_buffer(self, id)
+    }
+
+    fn delete_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_buffer_by_time(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn read_buffer_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send
+    {
+        Resource::read_buffer_timestamp(self, device_id, model_id, timestamp, tag)
+    }
+
+    fn list_buffer_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_timestamp_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn list_buffer_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_timestamp_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn list_buffer_timestamp_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_timestamp_first(self, number, device_id, model_id, tag)
+    }
+
+    fn list_buffer_timestamp_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_timestamp_last(self, number, device_id, model_id, tag)
+    }
+
+    fn read_buffer_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<DateTime<Utc>, Status>> + Send
+    {
+        Resource::read_buffer_group_timestamp(self, device_ids, model_ids, timestamp, tag)
+    }
+
+    fn list_buffer_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_group_timestamp_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn list_buffer_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_group_timestamp_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+    fn list_buffer_group_timestamp_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_group_timestamp_first(self, number, device_ids, model_ids, tag)
+    }
+
+    fn list_buffer_group_timestamp_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<DateTime<Utc>>, Status>> + Send
+    {
+        Resource::list_buffer_group_timestamp_last(self, number, device_ids, model_ids, tag)
+    }
+
+    fn count_buffer(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer(self, device_id, model_id, tag)
+    }
+
+    fn count_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer_by_latest(self, device_id, model_id, latest, tag)
+    }
+
+    fn count_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer_by_range(self, device_id, model_id, begin, end, tag)
+    }
+
+    fn count_buffer_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer_group(self, device_ids, model_ids, tag)
+    }
+
+    fn count_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer_group_by_latest(self, device_ids, model_ids, latest, tag)
+    }
+
+    fn count_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Future<Output = Result<usize, Status>> + Send
+    {
+        Resource::count_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag)
+    }
+
+}
+
+impl SliceApi for Resource {
+
+    fn read_slice(&self, id: i32)
+        -> impl Future<Output = Result<SliceSchema, Status>> + Send
+    {
+        Resource::read_slice(self, id)
+    }
+
+    fn list_slice_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_by_ids(self, ids)
+    }
+
+    fn list_slice_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_by_time(self, device_id, model_id, timestamp)
+    }
+
+    fn list_slice_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_by_range(self, device_id, model_id, begin, end)
+    }
+
+    fn list_slice_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_by_name_time(self, name, timestamp)
+    }
+
+    fn list_slice_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_by_name_range(self, name, begin, end)
+    }
+
+    fn list_slice_option(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_option(self, device_id, model_id, name, begin_or_timestamp, end)
+    }
+
+    fn list_slice_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_group_by_time(self, device_ids, model_ids, timestamp)
+    }
+
+    fn list_slice_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_group_by_range(self, device_ids, model_ids, begin, end)
+    }
+
+    fn list_slice_group_option(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSchema>, Status>> + Send
+    {
+        Resource::list_slice_group_option(self, device_ids, model_ids, name, begin_or_timestamp, end)
+    }
+
+    fn create_slice(&self, device_id: Uuid, model_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_slice(self, device_id, model_id, timestamp_begin, timestamp_end, name, description)
+    }
+
+    fn update_slice(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_slice(self, id, timestamp_begin, timestamp_end, name, description)
+    }
+
+    fn delete_slice(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_slice(self, id)
+    }
+
+    fn read_slice_set(&self, id: i32)
+        -> impl Future<Output = Result<SliceSetSchema, Status>> + Send
+    {
+        Resource::read_slice_set(self, id)
+    }
+
+    fn list_slice_set_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_by_ids(self, ids)
+    }
+
+    fn list_slice_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_by_time(self, set_id, timestamp)
+    }
+
+    fn list_slice_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_by_range(self, set_id, begin, end)
+    }
+
+    fn list_slice_set_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_by_name_time(self, name, timestamp)
+    }
+
+    fn list_slice_set_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_by_name_range(self, name, begin, end)
+    }
+
+    fn list_slice_set_option(&self, set_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
+        -> impl Future<Output = Result<Vec<SliceSetSchema>, Status>> + Send
+    {
+        Resource::list_slice_set_option(self, set_id, name, begin_or_timestamp, end)
+    }
+
+    fn create_slice_set(&self, set_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_slice_set(self, set_id, timestamp_begin, timestamp_end, name, description)
+    }
+
+    fn update_slice_set(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_slice_set(self, id, timestamp_begin, timestamp_end, name, description)
+    }
+
+    fn delete_slice_set(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_slice_set(self, id)
+    }
+
+}
+
+impl LogApi for Resource {
+
+    fn read_log(&self, id: i32)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log(self, id)
+    }
+
+    fn read_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log_by_time(self, timestamp, device_id, model_id, tag)
+    }
+
+    fn list_log_by_ids(&self, ids: &[i32])
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_by_ids(self, ids)
+    }
+
+    fn list_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_by_time(self, timestamp, device_id, model_id, tag)
+    }
+
+    fn list_log_by_latest(&self, latest: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_by_latest(self, latest, device_id, model_id, tag)
+    }
+
+    fn list_log_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_by_range(self, begin, end, device_id, model_id, tag)
+    }
+
+    fn read_log_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log_first(self, device_id, model_id, tag)
+    }
+
+    fn read_log_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log_last(self, device_id, model_id, tag)
+    }
+
+    fn list_log_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_first(self, number, device_id, model_id, tag)
+    }
+
+    fn list_log_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_first_offset(self, number, offset, device_id, model_id, tag)
+    }
+
+    fn list_log_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_last(self, number, device_id, model_id, tag)
+    }
+
+    fn list_log_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_last_offset(self, number, offset, device_id, model_id, tag)
+    }
+
+    fn list_log_group_by_time(&self, timestamp: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_by_time(self, timestamp, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_by_latest(&self, latest: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_by_latest(self, latest, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_by_range(self, begin, end, device_ids, model_ids, tag)
+    }
+
+    fn read_log_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log_group_first(self, device_ids, model_ids, tag)
+    }
+
+    fn read_log_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<LogSchema, Status>> + Send
+    {
+        Resource::read_log_group_last(self, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_first(self, number, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_first_offset(self, number, offset, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_last(self, number, device_ids, model_ids, tag)
+    }
+
+    fn list_log_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
+        -> impl Future<Output = Result<Vec<LogSchema>, Status>> + Send
+    {
+        Resource::list_log_group_last_offset(self, number, offset, device_ids, model_ids, tag)
+    }
+
+    fn create_log(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: DataValue, tag: Option<i16>)
+        -> impl Future<Output = Result<i32, Status>> + Send
+    {
+        Resource::create_log(self, timestamp, device_id, model_id, value, tag)
+    }
+
+    fn update_log(&self, id: i32, value: Option<DataValue>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_log(self, id, value, tag)
+    }
+
+    fn update_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: Option<DataValue>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::update_log_by_time(self, timestamp, device_id, model_id, value, tag)
+    }
+
+    fn delete_log(&self, id: i32)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_log(self, id)
+    }
+
+    fn delete_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
+        -> impl Future<Output = Result<(), Status>> + Send
+    {
+        Resource::delete_log_by_time(self, timestamp, device_id, model_id, tag)
+    }
+
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::Sender;
+use tokio::task::JoinHandle;
+use tonic::{Code, Request};
+use tonic::transport::{Channel, Endpoint};
+use tonic::transport::channel::Change;
+use uuid::Uuid;
+use rmcs_resource_api::model::ModelId;
+use rmcs_resource_api::model::model_service_client::ModelServiceClient;
+use crate::session::api_address;
+
+const BALANCE_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceConfig {
+    pub health_interval: Duration,
+    pub health_timeout: Duration
+}
+
+impl Default for BalanceConfig {
+    fn default() -> Self {
+        BalanceConfig {
+            health_interval: Duration::from_secs(5),
+            health_timeout: Duration::from_secs(2)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStatus {
+    pub address: String,
+    pub healthy: bool
+}
+
+type Discover = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Vec<String>> + Send>> + Send + Sync>;
+
+pub(crate) struct Balancer {
+    status: Arc<RwLock<Vec<EndpointStatus>>>,
+    members: Arc<Mutex<Members>>,
+    task: JoinHandle<()>
+}
+
+impl fmt::Debug for Balancer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Balancer").field("status", &self.status()).finish()
+    }
+}
+
+impl Balancer {
+
+    pub(crate) fn status(&self) -> Vec<EndpointStatus> {
+        self.status.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub(crate) fn is_available(&self) -> bool {
+        self.status.read().unwrap_or_else(|e| e.into_inner()).iter().any(|s| s.healthy)
+    }
+
+    // check endpoints now instead of waiting for the next interval, return whether any endpoint is healthy
+    pub(crate) async fn check(&self) -> bool {
+        update_status(&self.members, &self.status).await;
+        self.is_available()
+    }
+
+}
+
+// health check task is stopped when the last resource clone is dropped
+impl Drop for Balancer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct Member {
+    endpoint: Endpoint,
+    probe: ModelServiceClient<Channel>,
+    healthy: bool
+}
+
+struct Members {
+    discover: Discover,
+    members: HashMap<String, Member>,
+    sender: Sender<Change<String, Endpoint>>,
+    config: BalanceConfig
+}
+
+// any response from the server means the server is alive, including not found or unauthenticated
+async fn check_health(probe: &mut ModelServiceClient<Channel>, timeout: Duration) -> bool {
+    let request = Request::new(ModelId { id: Uuid::nil().as_bytes().to_vec() });
+    match tokio::time::timeout(timeout, probe.read_model(request)).await {
+        Ok(Ok(_)) => true,
+        Ok(Err(status)) => !matches!(status.code(), Code::Unavailable | Code::DeadlineExceeded | Code::Cancelled | Code::Unknown),
+        Err(_) => false
+    }
+}
+
+impl Members {
+    async fn update(&mut self) -> Vec<EndpointStatus> {
+        let addresses: Vec<String> = (self.discover)().await.iter().map(|a| api_address(a)).collect();
+        // remove endpoints that are no longer discovered
+        let removed: Vec<String> = self.members.keys().filter(|a| !addresses.contains(a)).cloned().collect();
+        for address in removed {
+            if self.members.remove(&address).is_some_and(|m| m.healthy) {
+                let _ = self.sender.send(Change::Remove(address)).await;
+            }
+        }
+        let mut status = Vec::new();
+        for address in addresses {
+            if !self.members.contains_key(&address) {
+                let endpoint = match Endpoint::from_shared(address.clone()) {
+                    Ok(endpoint) => endpoint,
+                    Err(_) => {
+                        status.push(EndpointStatus { address, healthy: false });
+                        continue;
+                    }
+                };
+                let probe = ModelServiceClient::new(endpoint.connect_lazy());
+                self.members.insert(address.clone(), Member { endpoint, probe, healthy: false });
+            }
+            let member = self.members.get_mut(&address).unwrap();
+            let healthy = check_health(&mut member.probe, self.config.health_timeout).await;
+            // eject unhealthy endpoint from balance channel and add it back when it is recovered
+            if healthy && !member.healthy {
+                let _ = self.sender.send(Change::Insert(address.clone(), member.endpoint.clone())).await;
+            } else if !healthy && member.healthy {
+                let _ = self.sender.send(Change::Remove(address.clone())).await;
+            }
+            member.healthy = healthy;
+            status.push(EndpointStatus { address, healthy });
+        }
+        status
+    }
+}
+
+async fn update_status(members: &Mutex<Members>, status: &RwLock<Vec<EndpointStatus>>) {
+    let new_status = members.lock().await.update().await;
+    *status.write().unwrap_or_else(|e| e.into_inner()) = new_status;
+}
+
+pub(crate) async fn balance_channel<F, Fut>(discover: F, config: BalanceConfig)
+    -> (Channel, Balancer)
+    where F: Fn() -> Fut + Send + Sync + 'static, Fut: Future<Output = Vec<String>> + Send + 'static
+{
+    let (channel, sender) = Channel::balance_channel(BALANCE_CAPACITY);
+    let health_interval = config.health_interval;
+    let discover: Discover = Box::new(move || Box::pin(discover()));
+    let members = Arc::new(Mutex::new(Members { discover, members: HashMap::new(), sender, config }));
+    let status = Arc::new(RwLock::new(Vec::new()));
+    // first health check is done before returning so healthy endpoints are ready to use
+    update_status(&members, &status).await;
+    let (members_task, status_task) = (members.clone(), status.clone());
+    let task = tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(health_interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            update_status(&members_task, &status_task).await;
+        }
+    });
+    (channel, Balancer { status, members, task })
+}
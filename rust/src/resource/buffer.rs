@@ -3,7 +3,6 @@ use chrono::{DateTime, TimeZone, Utc};
 use uuid::Uuid;
 use rmcs_resource_db::schema::value::{DataValue, ArrayDataValue};
 use rmcs_resource_db::tag as Tag;
-use rmcs_resource_api::buffer::buffer_service_client::BufferServiceClient;
 use rmcs_resource_api::buffer::{
     BufferSchema, BufferMultipleSchema, BufferId, BufferIds, BufferTime, BufferLatest, BufferRange, BufferNumber, 
     BufferSelector, BuffersSelector, BufferUpdate, BufferUpdateTime, 
@@ -11,7 +10,6 @@ use rmcs_resource_api::buffer::{
     BufferSetSchema, BufferSetTime, BufferSetLatest, BufferSetRange
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const BUFFER_NOT_FOUND: &str = "requested buffer not found";
 use super::data::EMPTY_LENGTH_UNMATCH;
@@ -19,9 +17,7 @@ use super::data::EMPTY_LENGTH_UNMATCH;
 pub(crate) async fn read_buffer(resource: &Resource, id: i32)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferId {
         id
     });
@@ -34,9 +30,7 @@ pub(crate) async fn read_buffer(resource: &Resource, id: i32)
 pub(crate) async fn read_buffer_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -52,9 +46,7 @@ pub(crate) async fn read_buffer_by_time(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn list_buffer_by_ids(resource: &Resource, ids: &[i32])
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferIds {
         ids: ids.to_vec()
     });
@@ -67,9 +59,7 @@ pub(crate) async fn list_buffer_by_ids(resource: &Resource, ids: &[i32])
 pub(crate) async fn list_buffer_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -85,9 +75,7 @@ pub(crate) async fn list_buffer_by_time(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn list_buffer_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -103,9 +91,7 @@ pub(crate) async fn list_buffer_by_latest(resource: &Resource, device_id: Uuid,
 pub(crate) async fn list_buffer_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -122,9 +108,7 @@ pub(crate) async fn list_buffer_by_range(resource: &Resource, device_id: Uuid, m
 pub(crate) async fn list_buffer_by_number_before(resource: &Resource, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferNumber {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -141,9 +125,7 @@ pub(crate) async fn list_buffer_by_number_before(resource: &Resource, device_id:
 pub(crate) async fn list_buffer_by_number_after(resource: &Resource, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferNumber {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -160,9 +142,7 @@ pub(crate) async fn list_buffer_by_number_after(resource: &Resource, device_id:
 pub(crate) async fn read_buffer_first(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -177,9 +157,7 @@ pub(crate) async fn read_buffer_first(resource: &Resource, device_id: Option<Uui
 pub(crate) async fn read_buffer_last(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -194,9 +172,7 @@ pub(crate) async fn read_buffer_last(resource: &Resource, device_id: Option<Uuid
 pub(crate) async fn list_buffer_first(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -213,9 +189,7 @@ pub(crate) async fn list_buffer_first(resource: &Resource, number: usize, device
 pub(crate) async fn list_buffer_first_offset(resource: &Resource, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -232,9 +206,7 @@ pub(crate) async fn list_buffer_first_offset(resource: &Resource, number: usize,
 pub(crate) async fn list_buffer_last(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -251,9 +223,7 @@ pub(crate) async fn list_buffer_last(resource: &Resource, number: usize, device_
 pub(crate) async fn list_buffer_last_offset(resource: &Resource, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -270,9 +240,7 @@ pub(crate) async fn list_buffer_last_offset(resource: &Resource, number: usize,
 pub(crate) async fn list_buffer_group_by_time(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -288,9 +256,7 @@ pub(crate) async fn list_buffer_group_by_time(resource: &Resource, device_ids: &
 pub(crate) async fn list_buffer_group_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -306,9 +272,7 @@ pub(crate) async fn list_buffer_group_by_latest(resource: &Resource, device_ids:
 pub(crate) async fn list_buffer_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -325,9 +289,7 @@ pub(crate) async fn list_buffer_group_by_range(resource: &Resource, device_ids:
 pub(crate) async fn list_buffer_group_by_number_before(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupNumber {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -344,9 +306,7 @@ pub(crate) async fn list_buffer_group_by_number_before(resource: &Resource, devi
 pub(crate) async fn list_buffer_group_by_number_after(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupNumber {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -363,9 +323,7 @@ pub(crate) async fn list_buffer_group_by_number_after(resource: &Resource, devic
 pub(crate) async fn read_buffer_group_first(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -380,9 +338,7 @@ pub(crate) async fn read_buffer_group_first(resource: &Resource, device_ids: Opt
 pub(crate) async fn read_buffer_group_last(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -397,9 +353,7 @@ pub(crate) async fn read_buffer_group_last(resource: &Resource, device_ids: Opti
 pub(crate) async fn list_buffer_group_first(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -416,9 +370,7 @@ pub(crate) async fn list_buffer_group_first(resource: &Resource, number: usize,
 pub(crate) async fn list_buffer_group_first_offset(resource: &Resource, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -435,9 +387,7 @@ pub(crate) async fn list_buffer_group_first_offset(resource: &Resource, number:
 pub(crate) async fn list_buffer_group_last(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -454,9 +404,7 @@ pub(crate) async fn list_buffer_group_last(resource: &Resource, number: usize, d
 pub(crate) async fn list_buffer_group_last_offset(resource: &Resource, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -473,9 +421,7 @@ pub(crate) async fn list_buffer_group_last_offset(resource: &Resource, number: u
 pub(crate) async fn read_buffer_set(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<BufferSetSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSetTime {
         set_id: set_id.as_bytes().to_vec(),
         timestamp: timestamp.timestamp_micros(),
@@ -490,9 +436,7 @@ pub(crate) async fn read_buffer_set(resource: &Resource, set_id: Uuid, timestamp
 pub(crate) async fn list_buffer_set_by_time(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSetTime {
         set_id: set_id.as_bytes().to_vec(),
         timestamp: timestamp.timestamp_micros(),
@@ -507,9 +451,7 @@ pub(crate) async fn list_buffer_set_by_time(resource: &Resource, set_id: Uuid, t
 pub(crate) async fn list_buffer_set_by_latest(resource: &Resource, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSetLatest {
         set_id: set_id.as_bytes().to_vec(),
         latest: latest.timestamp_micros(),
@@ -524,9 +466,7 @@ pub(crate) async fn list_buffer_set_by_latest(resource: &Resource, set_id: Uuid,
 pub(crate) async fn list_buffer_set_by_range(resource: &Resource, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<BufferSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSetRange {
         set_id: set_id.as_bytes().to_vec(),
         begin: begin.timestamp_micros(),
@@ -542,9 +482,7 @@ pub(crate) async fn list_buffer_set_by_range(resource: &Resource, set_id: Uuid,
 pub(crate) async fn create_buffer(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferSchema {
         id: 0,
         device_id: device_id.as_bytes().to_vec(),
@@ -563,9 +501,7 @@ pub(crate) async fn create_buffer(resource: &Resource, device_id: Uuid, model_id
 pub(crate) async fn create_buffer_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
     -> Result<Vec<i32>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let number = device_ids.len();
     let tags = match tags {
         Some(value) => value.to_vec(),
@@ -594,9 +530,7 @@ pub(crate) async fn create_buffer_multiple(resource: &Resource, device_ids: &[Uu
 pub(crate) async fn update_buffer(resource: &Resource, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferUpdate {
         id,
         data_bytes: data.as_deref().map(|v| ArrayDataValue::from_vec(v).to_bytes()),
@@ -611,9 +545,7 @@ pub(crate) async fn update_buffer(resource: &Resource, id: i32, data: Option<&[D
 pub(crate) async fn update_buffer_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferUpdateTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -630,9 +562,7 @@ pub(crate) async fn update_buffer_by_time(resource: &Resource, device_id: Uuid,
 pub(crate) async fn delete_buffer(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferId {
         id
     });
@@ -644,9 +574,7 @@ pub(crate) async fn delete_buffer(resource: &Resource, id: i32)
 pub(crate) async fn delete_buffer_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -661,9 +589,7 @@ pub(crate) async fn delete_buffer_by_time(resource: &Resource, device_id: Uuid,
 pub(crate) async fn read_buffer_timestamp(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -679,9 +605,7 @@ pub(crate) async fn read_buffer_timestamp(resource: &Resource, device_id: Uuid,
 pub(crate) async fn list_buffer_timestamp_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -697,9 +621,7 @@ pub(crate) async fn list_buffer_timestamp_by_latest(resource: &Resource, device_
 pub(crate) async fn list_buffer_timestamp_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -716,9 +638,7 @@ pub(crate) async fn list_buffer_timestamp_by_range(resource: &Resource, device_i
 pub(crate) async fn list_buffer_timestamp_first(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -735,9 +655,7 @@ pub(crate) async fn list_buffer_timestamp_first(resource: &Resource, number: usi
 pub(crate) async fn list_buffer_timestamp_last(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-    BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -754,9 +672,7 @@ pub(crate) async fn list_buffer_timestamp_last(resource: &Resource, number: usiz
 pub(crate) async fn read_buffer_group_timestamp(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -772,9 +688,7 @@ pub(crate) async fn read_buffer_group_timestamp(resource: &Resource, device_ids:
 pub(crate) async fn list_buffer_group_timestamp_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -790,9 +704,7 @@ pub(crate) async fn list_buffer_group_timestamp_by_latest(resource: &Resource, d
 pub(crate) async fn list_buffer_group_timestamp_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -809,9 +721,7 @@ pub(crate) async fn list_buffer_group_timestamp_by_range(resource: &Resource, de
 pub(crate) async fn list_buffer_group_timestamp_first(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -828,9 +738,7 @@ pub(crate) async fn list_buffer_group_timestamp_first(resource: &Resource, numbe
 pub(crate) async fn list_buffer_group_timestamp_last(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BuffersGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -847,9 +755,7 @@ pub(crate) async fn list_buffer_group_timestamp_last(resource: &Resource, number
 pub(crate) async fn count_buffer(resource: &Resource, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -865,9 +771,7 @@ pub(crate) async fn count_buffer(resource: &Resource, device_id: Uuid, model_id:
 pub(crate) async fn count_buffer_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -883,9 +787,7 @@ pub(crate) async fn count_buffer_by_latest(resource: &Resource, device_id: Uuid,
 pub(crate) async fn count_buffer_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -902,9 +804,7 @@ pub(crate) async fn count_buffer_by_range(resource: &Resource, device_id: Uuid,
 pub async fn count_buffer_group(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -920,9 +820,7 @@ pub async fn count_buffer_group(resource: &Resource, device_ids: &[Uuid], model_
 pub(crate) async fn count_buffer_group_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -938,9 +836,7 @@ pub(crate) async fn count_buffer_group_by_latest(resource: &Resource, device_ids
 pub(crate) async fn count_buffer_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.buffer.clone();
     let request = Request::new(BufferGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
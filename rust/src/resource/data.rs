@@ -3,14 +3,12 @@ use chrono::{DateTime, Utc, TimeZone};
 use uuid::Uuid;
 use rmcs_resource_db::schema::value::{DataValue, ArrayDataValue};
 use rmcs_resource_db::tag as Tag;
-use rmcs_resource_api::data::data_service_client::DataServiceClient;
 use rmcs_resource_api::data::{
     DataSchema, DataMultipleSchema, DataTime, DataLatest, DataRange, DataNumber, 
     DataGroupTime, DataGroupLatest, DataGroupRange, DataGroupNumber,
     DataSetSchema, DataSetTime, DataSetLatest, DataSetRange
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const DATA_NOT_FOUND: &str = "requested data not found";
 pub(crate) const EMPTY_LENGTH_UNMATCH: &str = "One or more input array arguments are empty or doesn't have the same length";
@@ -18,9 +16,7 @@ pub(crate) const EMPTY_LENGTH_UNMATCH: &str = "One or more input array arguments
 pub(crate) async fn read_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DataSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -36,9 +32,7 @@ pub(crate) async fn read_data(resource: &Resource, device_id: Uuid, model_id: Uu
 pub(crate) async fn list_data_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -54,9 +48,7 @@ pub(crate) async fn list_data_by_time(resource: &Resource, device_id: Uuid, mode
 pub(crate) async fn list_data_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -72,9 +64,7 @@ pub(crate) async fn list_data_by_latest(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn list_data_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -91,9 +81,7 @@ pub(crate) async fn list_data_by_range(resource: &Resource, device_id: Uuid, mod
 pub(crate) async fn list_data_by_number_before(resource: &Resource, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataNumber {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -110,9 +98,7 @@ pub(crate) async fn list_data_by_number_before(resource: &Resource, device_id: U
 pub(crate) async fn list_data_by_number_after(resource: &Resource, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataNumber {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -129,9 +115,7 @@ pub(crate) async fn list_data_by_number_after(resource: &Resource, device_id: Uu
 pub(crate) async fn list_data_group_by_time(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -147,9 +131,7 @@ pub(crate) async fn list_data_group_by_time(resource: &Resource, device_ids: &[U
 pub(crate) async fn list_data_group_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -165,9 +147,7 @@ pub(crate) async fn list_data_group_by_latest(resource: &Resource, device_ids: &
 pub(crate) async fn list_data_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -184,9 +164,7 @@ pub(crate) async fn list_data_group_by_range(resource: &Resource, device_ids: &[
 pub(crate) async fn list_data_group_by_number_before(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupNumber {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -203,9 +181,7 @@ pub(crate) async fn list_data_group_by_number_before(resource: &Resource, device
 pub(crate) async fn list_data_group_by_number_after(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupNumber {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -222,9 +198,7 @@ pub(crate) async fn list_data_group_by_number_after(resource: &Resource, device_
 pub(crate) async fn read_data_set(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DataSetSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataSetTime {
         set_id: set_id.as_bytes().to_vec(),
         timestamp: timestamp.timestamp_micros(),
@@ -239,9 +213,7 @@ pub(crate) async fn read_data_set(resource: &Resource, set_id: Uuid, timestamp:
 pub(crate) async fn list_data_set_by_time(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataSetTime {
         set_id: set_id.as_bytes().to_vec(),
         timestamp: timestamp.timestamp_micros(),
@@ -256,9 +228,7 @@ pub(crate) async fn list_data_set_by_time(resource: &Resource, set_id: Uuid, tim
 pub(crate) async fn list_data_set_by_latest(resource: &Resource, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataSetLatest {
         set_id: set_id.as_bytes().to_vec(),
         latest: latest.timestamp_micros(),
@@ -273,9 +243,7 @@ pub(crate) async fn list_data_set_by_latest(resource: &Resource, set_id: Uuid, l
 pub(crate) async fn list_data_set_by_range(resource: &Resource, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DataSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataSetRange {
         set_id: set_id.as_bytes().to_vec(),
         begin: begin.timestamp_micros(),
@@ -291,9 +259,7 @@ pub(crate) async fn list_data_set_by_range(resource: &Resource, set_id: Uuid, be
 pub(crate) async fn create_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataSchema {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -310,9 +276,7 @@ pub(crate) async fn create_data(resource: &Resource, device_id: Uuid, model_id:
 pub(crate) async fn create_data_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let number = device_ids.len();
     let tags = match tags {
         Some(value) => value.to_vec(),
@@ -339,9 +303,7 @@ pub(crate) async fn create_data_multiple(resource: &Resource, device_ids: &[Uuid
 pub(crate) async fn delete_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -356,9 +318,7 @@ pub(crate) async fn delete_data(resource: &Resource, device_id: Uuid, model_id:
 pub(crate) async fn read_data_timestamp(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -374,9 +334,7 @@ pub(crate) async fn read_data_timestamp(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn list_data_timestamp_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -392,9 +350,7 @@ pub(crate) async fn list_data_timestamp_by_latest(resource: &Resource, device_id
 pub(crate) async fn list_data_timestamp_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -411,9 +367,7 @@ pub(crate) async fn list_data_timestamp_by_range(resource: &Resource, device_id:
 pub(crate) async fn read_data_group_timestamp(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -429,9 +383,7 @@ pub(crate) async fn read_data_group_timestamp(resource: &Resource, device_ids: &
 pub(crate) async fn list_data_group_timestamp_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -447,9 +399,7 @@ pub(crate) async fn list_data_group_timestamp_by_latest(resource: &Resource, dev
 pub(crate) async fn list_data_group_timestamp_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<Vec<DateTime<Utc>>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -466,9 +416,7 @@ pub(crate) async fn list_data_group_timestamp_by_range(resource: &Resource, devi
 pub(crate) async fn count_data(resource: &Resource, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -484,9 +432,7 @@ pub(crate) async fn count_data(resource: &Resource, device_id: Uuid, model_id: U
 pub(crate) async fn count_data_by_latest(resource: &Resource, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataLatest {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -502,9 +448,7 @@ pub(crate) async fn count_data_by_latest(resource: &Resource, device_id: Uuid, m
 pub(crate) async fn count_data_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -521,9 +465,7 @@ pub(crate) async fn count_data_by_range(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn count_data_group(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -539,9 +481,7 @@ pub(crate) async fn count_data_group(resource: &Resource, device_ids: &[Uuid], m
 pub(crate) async fn count_data_group_by_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupLatest {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -557,9 +497,7 @@ pub(crate) async fn count_data_group_by_latest(resource: &Resource, device_ids:
 pub(crate) async fn count_data_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
     -> Result<usize, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.data.clone();
     let request = Request::new(DataGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
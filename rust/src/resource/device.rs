@@ -1,7 +1,6 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
 use rmcs_resource_db::schema::value::DataValue;
-use rmcs_resource_api::device::device_service_client::DeviceServiceClient;
 use rmcs_resource_api::device::{
     DeviceSchema, DeviceId, DeviceIds, DeviceName, DeviceOption, DeviceUpdate,
     GatewaySchema, GatewayId, GatewayIds, GatewayName, SerialNumber, GatewayOption, GatewayUpdate,
@@ -9,7 +8,6 @@ use rmcs_resource_api::device::{
     TypeSchema, TypeId
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const DEVICE_NOT_FOUND: &str = "requested device not found";
 const GATEWAY_NOT_FOUND: &str = "requested gateway not found";
@@ -18,9 +16,7 @@ const CONF_NOT_FOUND: &str = "requested config not found";
 pub(crate) async fn read_device(resource: &Resource, id: Uuid)
     -> Result<DeviceSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceId {
         id: id.as_bytes().to_vec()
     });
@@ -33,9 +29,7 @@ pub(crate) async fn read_device(resource: &Resource, id: Uuid)
 pub(crate) async fn read_device_by_sn(resource: &Resource, serial_number: &str)
     -> Result<DeviceSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(SerialNumber {
         serial_number: serial_number.to_owned()
     });
@@ -48,9 +42,7 @@ pub(crate) async fn read_device_by_sn(resource: &Resource, serial_number: &str)
 pub(crate) async fn list_device_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<DeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -63,9 +55,7 @@ pub(crate) async fn list_device_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_device_by_gateway(resource: &Resource, gateway_id: Uuid)
     -> Result<Vec<DeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayId {
         id: gateway_id.as_bytes().to_vec()
     });
@@ -78,9 +68,7 @@ pub(crate) async fn list_device_by_gateway(resource: &Resource, gateway_id: Uuid
 pub(crate) async fn list_device_by_type(resource: &Resource, type_id: Uuid)
     -> Result<Vec<DeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeId {
         id: type_id.as_bytes().to_vec()
     });
@@ -93,9 +81,7 @@ pub(crate) async fn list_device_by_type(resource: &Resource, type_id: Uuid)
 pub(crate) async fn list_device_by_name(resource: &Resource, name: &str)
     -> Result<Vec<DeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceName {
         name: name.to_owned()
     });
@@ -108,9 +94,7 @@ pub(crate) async fn list_device_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_device_option(resource: &Resource, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<DeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceOption {
         gateway_id: gateway_id.map(|id| id.as_bytes().to_vec()),
         type_id: type_id.map(|id| id.as_bytes().to_vec()),
@@ -125,9 +109,7 @@ pub(crate) async fn list_device_option(resource: &Resource, gateway_id: Option<U
 pub(crate) async fn create_device(resource: &Resource, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceSchema {
         id: id.as_bytes().to_vec(),
         gateway_id: gateway_id.as_bytes().to_vec(),
@@ -150,9 +132,7 @@ pub(crate) async fn create_device(resource: &Resource, id: Uuid, gateway_id: Uui
 pub(crate) async fn update_device(resource: &Resource, id: Uuid, gateway_id: Option<Uuid>, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceUpdate {
         id: id.as_bytes().to_vec(),
         gateway_id: gateway_id.map(|x| x.as_bytes().to_vec()),
@@ -169,9 +149,7 @@ pub(crate) async fn update_device(resource: &Resource, id: Uuid, gateway_id: Opt
 pub(crate) async fn delete_device(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceId {
         id: id.as_bytes().to_vec()
     });
@@ -183,9 +161,7 @@ pub(crate) async fn delete_device(resource: &Resource, id: Uuid)
 pub(crate) async fn read_gateway(resource: &Resource, id: Uuid)
     -> Result<GatewaySchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayId {
         id: id.as_bytes().to_vec()
     });
@@ -198,9 +174,7 @@ pub(crate) async fn read_gateway(resource: &Resource, id: Uuid)
 pub(crate) async fn read_gateway_by_sn(resource: &Resource, serial_number: &str)
     -> Result<GatewaySchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(SerialNumber {
         serial_number: serial_number.to_owned()
     });
@@ -213,9 +187,7 @@ pub(crate) async fn read_gateway_by_sn(resource: &Resource, serial_number: &str)
 pub(crate) async fn list_gateway_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<GatewaySchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -228,9 +200,7 @@ pub(crate) async fn list_gateway_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_gateway_by_type(resource: &Resource, type_id: Uuid)
     -> Result<Vec<GatewaySchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeId {
         id: type_id.as_bytes().to_vec()
     });
@@ -243,9 +213,7 @@ pub(crate) async fn list_gateway_by_type(resource: &Resource, type_id: Uuid)
 pub(crate) async fn list_gateway_by_name(resource: &Resource, name: &str)
     -> Result<Vec<GatewaySchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayName {
         name: name.to_owned()
     });
@@ -258,9 +226,7 @@ pub(crate) async fn list_gateway_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_gateway_option(resource: &Resource, type_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<GatewaySchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayOption {
         type_id: type_id.map(|id| id.as_bytes().to_vec()),
         name: name.map(|s| s.to_owned())
@@ -274,9 +240,7 @@ pub(crate) async fn list_gateway_option(resource: &Resource, type_id: Option<Uui
 pub(crate) async fn create_gateway(resource: &Resource, id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewaySchema {
         id: id.as_bytes().to_vec(),
         serial_number: serial_number.to_owned(),
@@ -298,9 +262,7 @@ pub(crate) async fn create_gateway(resource: &Resource, id: Uuid, type_id: Uuid,
 pub(crate) async fn update_gateway(resource: &Resource, id: Uuid, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayUpdate {
         id: id.as_bytes().to_vec(),
         serial_number: serial_number.map(|s| s.to_owned()),
@@ -316,9 +278,7 @@ pub(crate) async fn update_gateway(resource: &Resource, id: Uuid, type_id: Optio
 pub(crate) async fn delete_gateway(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayId {
         id: id.as_bytes().to_vec()
     });
@@ -330,9 +290,7 @@ pub(crate) async fn delete_gateway(resource: &Resource, id: Uuid)
 pub(crate) async fn read_device_config(resource: &Resource, id: i32)
     -> Result<ConfigSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigId {
         id
     });
@@ -345,9 +303,7 @@ pub(crate) async fn read_device_config(resource: &Resource, id: i32)
 pub(crate) async fn list_device_config_by_device(resource: &Resource, device_id: Uuid)
     -> Result<Vec<ConfigSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(DeviceId {
         id: device_id.as_bytes().to_vec()
     });
@@ -360,9 +316,7 @@ pub(crate) async fn list_device_config_by_device(resource: &Resource, device_id:
 pub(crate) async fn create_device_config(resource: &Resource, device_id: Uuid, name: &str, value: DataValue, category: &str)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigSchema {
         id: 0,
         device_id: device_id.as_bytes().to_vec(),
@@ -380,9 +334,7 @@ pub(crate) async fn create_device_config(resource: &Resource, device_id: Uuid, n
 pub(crate) async fn update_device_config(resource: &Resource, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigUpdate {
         id,
         name: name.map(|s| s.to_owned()),
@@ -398,9 +350,7 @@ pub(crate) async fn update_device_config(resource: &Resource, id: i32, name: Opt
 pub(crate) async fn delete_device_config(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigId {
         id
     });
@@ -412,9 +362,7 @@ pub(crate) async fn delete_device_config(resource: &Resource, id: i32)
 pub(crate) async fn read_gateway_config(resource: &Resource, id: i32)
     -> Result<ConfigSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigId {
         id
     });
@@ -427,9 +375,7 @@ pub(crate) async fn read_gateway_config(resource: &Resource, id: i32)
 pub(crate) async fn list_gateway_config_by_gateway(resource: &Resource, gateway_id: Uuid)
     -> Result<Vec<ConfigSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(GatewayId {
         id: gateway_id.as_bytes().to_vec()
     });
@@ -442,9 +388,7 @@ pub(crate) async fn list_gateway_config_by_gateway(resource: &Resource, gateway_
 pub(crate) async fn create_gateway_config(resource: &Resource, gateway_id: Uuid, name: &str, value: DataValue, category: &str)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigSchema {
         id: 0,
         device_id: gateway_id.as_bytes().to_vec(),
@@ -462,9 +406,7 @@ pub(crate) async fn create_gateway_config(resource: &Resource, gateway_id: Uuid,
 pub(crate) async fn update_gateway_config(resource: &Resource, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigUpdate {
         id,
         name: name.map(|s| s.to_owned()),
@@ -480,9 +422,7 @@ pub(crate) async fn update_gateway_config(resource: &Resource, id: i32, name: Op
 pub(crate) async fn delete_gateway_config(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(ConfigId {
         id
     });
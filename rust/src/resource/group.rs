@@ -1,21 +1,17 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_resource_api::group::group_service_client::GroupServiceClient;
 use rmcs_resource_api::group::{
     GroupModelSchema, GroupDeviceSchema, GroupId, GroupIds, GroupName, GroupOption, GroupCategory, GroupUpdate,
     GroupModel, GroupDevice
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const GROUP_NOT_FOUND: &str = "requested group not found";
 
 pub(crate) async fn read_group_model(resource: &Resource, id: Uuid)
     -> Result<GroupModelSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -28,9 +24,7 @@ pub(crate) async fn read_group_model(resource: &Resource, id: Uuid)
 pub(crate) async fn list_group_model_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<GroupModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -43,9 +37,7 @@ pub(crate) async fn list_group_model_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_group_model_by_name(resource: &Resource, name: &str)
     -> Result<Vec<GroupModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupName {
         name: name.to_owned()
     });
@@ -58,9 +50,7 @@ pub(crate) async fn list_group_model_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_group_model_by_category(resource: &Resource, category: &str)
     -> Result<Vec<GroupModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupCategory {
         category: category.to_owned()
     });
@@ -73,9 +63,7 @@ pub(crate) async fn list_group_model_by_category(resource: &Resource, category:
 pub(crate) async fn list_group_model_option(resource: &Resource, name: Option<&str>, category: Option<&str>)
     -> Result<Vec<GroupModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupOption {
         name: name.map(|s| s.to_owned()),
         category: category.map(|s| s.to_owned())
@@ -89,9 +77,7 @@ pub(crate) async fn list_group_model_option(resource: &Resource, name: Option<&s
 pub(crate) async fn create_group_model(resource: &Resource, id: Uuid, name: &str, category: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupModelSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -108,9 +94,7 @@ pub(crate) async fn create_group_model(resource: &Resource, id: Uuid, name: &str
 pub(crate) async fn update_group_model(resource: &Resource, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -125,9 +109,7 @@ pub(crate) async fn update_group_model(resource: &Resource, id: Uuid, name: Opti
 pub(crate) async fn delete_group_model(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -139,9 +121,7 @@ pub(crate) async fn delete_group_model(resource: &Resource, id: Uuid)
 pub(crate) async fn add_group_model_member(resource: &Resource, id: Uuid, model_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupModel {
         id: id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec()
@@ -154,9 +134,7 @@ pub(crate) async fn add_group_model_member(resource: &Resource, id: Uuid, model_
 pub(crate) async fn remove_group_model_member(resource: &Resource, id: Uuid, model_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupModel {
         id: id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec()
@@ -169,9 +147,7 @@ pub(crate) async fn remove_group_model_member(resource: &Resource, id: Uuid, mod
 pub(crate) async fn read_group_device(resource: &Resource, id: Uuid)
     -> Result<GroupDeviceSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -184,9 +160,7 @@ pub(crate) async fn read_group_device(resource: &Resource, id: Uuid)
 pub(crate) async fn list_group_device_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -199,9 +173,7 @@ pub(crate) async fn list_group_device_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_group_device_by_name(resource: &Resource, name: &str)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupName {
         name: name.to_owned()
     });
@@ -214,9 +186,7 @@ pub(crate) async fn list_group_device_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_group_device_by_category(resource: &Resource, category: &str)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupCategory {
         category: category.to_owned()
     });
@@ -229,9 +199,7 @@ pub(crate) async fn list_group_device_by_category(resource: &Resource, category:
 pub(crate) async fn list_group_device_option(resource: &Resource, name: Option<&str>, category: Option<&str>)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupOption {
         name: name.map(|s| s.to_owned()),
         category: category.map(|s| s.to_owned())
@@ -245,9 +213,7 @@ pub(crate) async fn list_group_device_option(resource: &Resource, name: Option<&
 pub(crate) async fn create_group_device(resource: &Resource, id: Uuid, name: &str, category: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDeviceSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -264,9 +230,7 @@ pub(crate) async fn create_group_device(resource: &Resource, id: Uuid, name: &st
 pub(crate) async fn update_group_device(resource: &Resource, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -281,9 +245,7 @@ pub(crate) async fn update_group_device(resource: &Resource, id: Uuid, name: Opt
 pub(crate) async fn delete_group_device(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -295,9 +257,7 @@ pub(crate) async fn delete_group_device(resource: &Resource, id: Uuid)
 pub(crate) async fn add_group_device_member(resource: &Resource, id: Uuid, device_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDevice {
         id: id.as_bytes().to_vec(),
         device_id: device_id.as_bytes().to_vec()
@@ -310,9 +270,7 @@ pub(crate) async fn add_group_device_member(resource: &Resource, id: Uuid, devic
 pub(crate) async fn remove_group_device_member(resource: &Resource, id: Uuid, device_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDevice {
         id: id.as_bytes().to_vec(),
         device_id: device_id.as_bytes().to_vec()
@@ -325,9 +283,7 @@ pub(crate) async fn remove_group_device_member(resource: &Resource, id: Uuid, de
 pub(crate) async fn read_group_gateway(resource: &Resource, id: Uuid)
     -> Result<GroupDeviceSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -340,9 +296,7 @@ pub(crate) async fn read_group_gateway(resource: &Resource, id: Uuid)
 pub(crate) async fn list_group_gateway_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -355,9 +309,7 @@ pub(crate) async fn list_group_gateway_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_group_gateway_by_name(resource: &Resource, name: &str)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupName {
         name: name.to_owned()
     });
@@ -370,9 +322,7 @@ pub(crate) async fn list_group_gateway_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_group_gateway_by_category(resource: &Resource, category: &str)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupCategory {
         category: category.to_owned()
     });
@@ -385,9 +335,7 @@ pub(crate) async fn list_group_gateway_by_category(resource: &Resource, category
 pub(crate) async fn list_group_gateway_option(resource: &Resource, name: Option<&str>, category: Option<&str>)
     -> Result<Vec<GroupDeviceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupOption {
         name: name.map(|s| s.to_owned()),
         category: category.map(|s| s.to_owned())
@@ -401,9 +349,7 @@ pub(crate) async fn list_group_gateway_option(resource: &Resource, name: Option<
 pub(crate) async fn create_group_gateway(resource: &Resource, id: Uuid, name: &str, category: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDeviceSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -420,9 +366,7 @@ pub(crate) async fn create_group_gateway(resource: &Resource, id: Uuid, name: &s
 pub(crate) async fn update_group_gateway(resource: &Resource, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -437,9 +381,7 @@ pub(crate) async fn update_group_gateway(resource: &Resource, id: Uuid, name: Op
 pub(crate) async fn delete_group_gateway(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupId {
         id: id.as_bytes().to_vec()
     });
@@ -451,9 +393,7 @@ pub(crate) async fn delete_group_gateway(resource: &Resource, id: Uuid)
 pub(crate) async fn add_group_gateway_member(resource: &Resource, id: Uuid, gateway_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDevice {
         id: id.as_bytes().to_vec(),
         device_id: gateway_id.as_bytes().to_vec()
@@ -466,9 +406,7 @@ pub(crate) async fn add_group_gateway_member(resource: &Resource, id: Uuid, gate
 pub(crate) async fn remove_group_gateway_member(resource: &Resource, id: Uuid, gateway_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        GroupServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.group.clone();
     let request = Request::new(GroupDevice {
         id: id.as_bytes().to_vec(),
         device_id: gateway_id.as_bytes().to_vec()
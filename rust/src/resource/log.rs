@@ -3,23 +3,19 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use rmcs_resource_db::schema::value::DataValue;
 use rmcs_resource_db::tag as Tag;
-use rmcs_resource_api::log::log_service_client::LogServiceClient;
 use rmcs_resource_api::log::{
     LogId, LogIds, LogTime, LogLatest, LogRange, LogSelector, LogsSelector, LogSchema,
     LogGroupTime, LogGroupLatest, LogGroupRange, LogGroupSelector, LogsGroupSelector,
     LogUpdate, LogUpdateTime
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const LOG_NOT_FOUND: &str = "requested log not found";
 
 pub(crate) async fn read_log(resource: &Resource, id: i32)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogId { id });
     let response = client.read_log(request)
         .await?
@@ -30,9 +26,7 @@ pub(crate) async fn read_log(resource: &Resource, id: i32)
 pub(crate) async fn read_log_by_time(resource: &Resource, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogTime {
         timestamp: timestamp.timestamp_micros(),
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
@@ -48,9 +42,7 @@ pub(crate) async fn read_log_by_time(resource: &Resource, timestamp: DateTime<Ut
 pub(crate) async fn list_log_by_ids(resource: &Resource, ids: &[i32])
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogIds {
         ids: ids.to_vec()
     });
@@ -63,9 +55,7 @@ pub(crate) async fn list_log_by_ids(resource: &Resource, ids: &[i32])
 pub(crate) async fn list_log_by_time(resource: &Resource, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogTime {
         timestamp: timestamp.timestamp_micros(),
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
@@ -81,9 +71,7 @@ pub(crate) async fn list_log_by_time(resource: &Resource, timestamp: DateTime<Ut
 pub(crate) async fn list_log_by_latest(resource: &Resource, latest: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogLatest {
         latest: latest.timestamp_micros(),
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
@@ -99,9 +87,7 @@ pub(crate) async fn list_log_by_latest(resource: &Resource, latest: DateTime<Utc
 pub(crate) async fn list_log_by_range(resource: &Resource, begin: DateTime<Utc>, end: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogRange {
         begin: begin.timestamp_micros(),
         end: end.timestamp_micros(),
@@ -118,9 +104,7 @@ pub(crate) async fn list_log_by_range(resource: &Resource, begin: DateTime<Utc>,
 pub async fn read_log_first(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -135,9 +119,7 @@ pub async fn read_log_first(resource: &Resource, device_id: Option<Uuid>, model_
 pub async fn read_log_last(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogSelector {
         device_id: device_id.map(|x| x.as_bytes().to_vec()),
         model_id: model_id.map(|x| x.as_bytes().to_vec()),
@@ -152,9 +134,7 @@ pub async fn read_log_last(resource: &Resource, device_id: Option<Uuid>, model_i
 pub async fn list_log_first(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsSelector {
         number: number as u32,
         offset: 0,
@@ -171,9 +151,7 @@ pub async fn list_log_first(resource: &Resource, number: usize, device_id: Optio
 pub async fn list_log_first_offset(resource: &Resource, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsSelector {
         number: number as u32,
         offset: offset as u32,
@@ -190,9 +168,7 @@ pub async fn list_log_first_offset(resource: &Resource, number: usize, offset: u
 pub async fn list_log_last(resource: &Resource, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsSelector {
         number: number as u32,
         offset: 0,
@@ -209,9 +185,7 @@ pub async fn list_log_last(resource: &Resource, number: usize, device_id: Option
 pub async fn list_log_last_offset(resource: &Resource, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsSelector {
         number: number as u32,
         offset: offset as u32,
@@ -228,9 +202,7 @@ pub async fn list_log_last_offset(resource: &Resource, number: usize, offset: us
 pub async fn list_log_group_by_time(resource: &Resource, timestamp: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogGroupTime {
         timestamp: timestamp.timestamp_micros(),
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -246,9 +218,7 @@ pub async fn list_log_group_by_time(resource: &Resource, timestamp: DateTime<Utc
 pub async fn list_log_group_by_latest(resource: &Resource, latest: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogGroupLatest {
         latest: latest.timestamp_micros(),
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -264,9 +234,7 @@ pub async fn list_log_group_by_latest(resource: &Resource, latest: DateTime<Utc>
 pub async fn list_log_group_by_range(resource: &Resource, begin: DateTime<Utc>, end: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogGroupRange {
         begin: begin.timestamp_micros(),
         end: end.timestamp_micros(),
@@ -283,9 +251,7 @@ pub async fn list_log_group_by_range(resource: &Resource, begin: DateTime<Utc>,
 pub async fn read_log_group_first(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -300,9 +266,7 @@ pub async fn read_log_group_first(resource: &Resource, device_ids: Option<&[Uuid
 pub async fn read_log_group_last(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<LogSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogGroupSelector {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -317,9 +281,7 @@ pub async fn read_log_group_last(resource: &Resource, device_ids: Option<&[Uuid]
 pub async fn list_log_group_first(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsGroupSelector {
         number: number as u32,
         offset: 0,
@@ -336,9 +298,7 @@ pub async fn list_log_group_first(resource: &Resource, number: usize, device_ids
 pub async fn list_log_group_first_offset(resource: &Resource, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsGroupSelector {
         number: number as u32,
         offset: offset as u32,
@@ -355,9 +315,7 @@ pub async fn list_log_group_first_offset(resource: &Resource, number: usize, off
 pub async fn list_log_group_last(resource: &Resource, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsGroupSelector {
         number: number as u32,
         offset: 0,
@@ -374,9 +332,7 @@ pub async fn list_log_group_last(resource: &Resource, number: usize, device_ids:
 pub async fn list_log_group_last_offset(resource: &Resource, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<Vec<LogSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogsGroupSelector {
         number: number as u32,
         offset: offset as u32,
@@ -393,9 +349,7 @@ pub async fn list_log_group_last_offset(resource: &Resource, number: usize, offs
 pub(crate) async fn create_log(resource: &Resource, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: DataValue, tag: Option<i16>)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogSchema {
         id: 0,
         timestamp: timestamp.timestamp_micros(),
@@ -414,9 +368,7 @@ pub(crate) async fn create_log(resource: &Resource, timestamp: DateTime<Utc>, de
 pub(crate) async fn update_log(resource: &Resource, id: i32, value: Option<DataValue>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogUpdate {
         id: id,
         log_bytes: value.clone().map(|s| s.to_bytes()),
@@ -431,9 +383,7 @@ pub(crate) async fn update_log(resource: &Resource, id: i32, value: Option<DataV
 pub(crate) async fn update_log_by_time(resource: &Resource, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: Option<DataValue>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogUpdateTime {
         timestamp: timestamp.timestamp_micros(),
         device_id: device_id.map(|id| id.as_bytes().to_vec()),
@@ -450,9 +400,7 @@ pub(crate) async fn update_log_by_time(resource: &Resource, timestamp: DateTime<
 pub(crate) async fn delete_log(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogId { id });
     client.delete_log(request)
         .await?;
@@ -462,9 +410,7 @@ pub(crate) async fn delete_log(resource: &Resource, id: i32)
 pub(crate) async fn delete_log_by_time(resource: &Resource, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        LogServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.log.clone();
     let request = Request::new(LogTime {
         timestamp: timestamp.timestamp_micros(),
         device_id: device_id.map(|id| id.as_bytes().to_vec()),
@@ -7,8 +7,31 @@ pub mod data;
 pub mod buffer;
 pub mod slice;
 pub mod log;
-
-use tonic::{Status, transport::Channel};
+pub mod balance;
+pub mod api;
+
+use std::future::Future;
+use std::time::Duration;
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+use tonic::{Code, Status, transport::Channel};
+use tonic::service::interceptor::InterceptedService;
+use rmcs_resource_api::model::model_service_client::ModelServiceClient;
+use rmcs_resource_api::device::device_service_client::DeviceServiceClient;
+use rmcs_resource_api::group::group_service_client::GroupServiceClient;
+use rmcs_resource_api::set::set_service_client::SetServiceClient;
+use rmcs_resource_api::data::data_service_client::DataServiceClient;
+use rmcs_resource_api::buffer::buffer_service_client::BufferServiceClient;
+use rmcs_resource_api::slice::slice_service_client::SliceServiceClient;
+use rmcs_resource_api::log::log_service_client::LogServiceClient;
+use crate::error::Error;
+use crate::settings::Settings;
+use crate::retry::RetryPolicy;
+use balance::Balancer;
+pub use balance::{BalanceConfig, EndpointStatus};
+pub use api::{ModelApi, DeviceApi, GroupApi, SetApi, DataApi, BufferApi, SliceApi, LogApi, ResourceApi};
+use crate::options::{CallOptions, CallInterceptor, CallToken, DEFAULT_TIMEOUT, LONG_TIMEOUT, deadline, with_call_options};
+use crate::auth::Auth;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 pub use rmcs_resource_db::schema::value::{DataType, DataValue, ArrayDataValue};
@@ -22,11 +45,52 @@ pub use rmcs_resource_db::schema::slice::{SliceSchema, SliceSetSchema};
 pub use rmcs_resource_db::schema::log::LogSchema;
 pub use rmcs_resource_db::utility::tag;
 
+const REFRESH_AUTH_EMPTY: &str = "auth client for refreshing token is not set";
+const ENDPOINT_UNAVAILABLE: &str = "no healthy resource server endpoint";
+
+type ResourceService = InterceptedService<Channel, CallInterceptor>;
+
+// generated clients are created once and cloned cheaply on every call
+#[derive(Debug, Clone)]
+struct ResourceClients {
+    model: ModelServiceClient<ResourceService>,
+    device: DeviceServiceClient<ResourceService>,
+    group: GroupServiceClient<ResourceService>,
+    set: SetServiceClient<ResourceService>,
+    data: DataServiceClient<ResourceService>,
+    buffer: BufferServiceClient<ResourceService>,
+    slice: SliceServiceClient<ResourceService>,
+    log: LogServiceClient<ResourceService>
+}
+
+impl ResourceClients {
+    fn new(channel: &Channel, interceptor: CallInterceptor) -> Self {
+        ResourceClients {
+            model: ModelServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            device: DeviceServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            group: GroupServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            set: SetServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            data: DataServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            buffer: BufferServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            slice: SliceServiceClient::with_interceptor(channel.to_owned(), interceptor.clone()),
+            log: LogServiceClient::with_interceptor(channel.to_owned(), interceptor)
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Resource {
     channel: Channel,
-    access_token: String,
-    refresh_token: String
+    token: Arc<RwLock<(String, String)>>,
+    auth: Option<Auth>,
+    api_id: Uuid,
+    refresh_lock: Arc<Mutex<()>>,
+    options: Arc<CallOptions>,
+    clients: Arc<ResourceClients>,
+    retry: RetryPolicy,
+    timeout: Option<Duration>,
+    long_timeout: Option<Duration>,
+    balancer: Option<Arc<Balancer>>
 }
 
 impl Resource {
@@ -37,31 +101,217 @@ impl Resource {
             .connect()
             .await
             .expect(&format!("Error making channel to {}", addr));
-        Resource {
-            channel,
-            access_token: String::new(),
-            refresh_token: String::new()
-        }
+        Self::new_with_channel(channel)
+    }
+
+    pub async fn try_new(addr: &str) -> Result<Self, Error> {
+        let channel = Channel::from_shared(addr.to_owned())
+            .map_err(|_| Error::InvalidAddress(addr.to_owned()))?
+            .connect()
+            .await?;
+        Ok(Self::new_with_channel(channel))
+    }
+
+    pub async fn from_env() -> Result<Self, Error> {
+        Settings::from_env()?.resource_config()?.resource().await
+    }
+
+    // balance calls across several resource server replicas
+    pub async fn new_balanced(addrs: &[&str], config: BalanceConfig) -> Self {
+        let addrs: Vec<String> = addrs.iter().map(|a| a.to_string()).collect();
+        Self::new_discovery(move || std::future::ready(addrs.clone()), config).await
+    }
+
+    // discover function is called on every health check interval to update endpoint list
+    pub async fn new_discovery<F, Fut>(discover: F, config: BalanceConfig) -> Self
+        where F: Fn() -> Fut + Send + Sync + 'static, Fut: Future<Output = Vec<String>> + Send + 'static
+    {
+        let (channel, balancer) = balance::balance_channel(discover, config).await;
+        let mut resource = Self::new_with_channel(channel);
+        resource.balancer = Some(Arc::new(balancer));
+        resource
     }
 
     pub fn new_with_channel(channel: Channel) -> Self {
+        let token = Arc::new(RwLock::new((String::new(), String::new())));
+        let interceptor = CallInterceptor::new(CallToken::Shared(token.clone()));
         Resource {
+            clients: Arc::new(ResourceClients::new(&channel, interceptor)),
             channel,
-            access_token: String::new(),
-            refresh_token: String::new()
+            token,
+            auth: None,
+            api_id: Uuid::nil(),
+            refresh_lock: Arc::new(Mutex::new(())),
+            options: Arc::new(CallOptions::default()),
+            retry: RetryPolicy::default(),
+            timeout: Some(DEFAULT_TIMEOUT),
+            long_timeout: Some(LONG_TIMEOUT),
+            balancer: None
         }
     }
 
+    pub fn endpoints(&self) -> Vec<EndpointStatus> {
+        self.balancer.as_ref().map(|b| b.status()).unwrap_or_default()
+    }
+
     pub fn with_token(mut self, access_token: &str, refresh_token: &str) -> Self {
-        self.access_token = access_token.to_owned();
-        self.refresh_token = refresh_token.to_owned();
+        self.token = Arc::new(RwLock::new((access_token.to_owned(), refresh_token.to_owned())));
+        let interceptor = CallInterceptor::new(CallToken::Shared(self.token.clone()));
+        self.clients = Arc::new(ResourceClients::new(&self.channel, interceptor));
+        self
+    }
+
+    pub fn with_auth(mut self, auth: &Auth, api_id: Uuid) -> Self {
+        self.auth = Some(auth.clone());
+        self.api_id = api_id;
+        self
+    }
+
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
         self
     }
 
+    // timeout for listing data, buffer and log over a time window
+    pub fn with_long_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.long_timeout = timeout;
+        self
+    }
+
+    pub fn token(&self) -> (String, String) {
+        self.token.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set_token(&self, access_token: &str, refresh_token: &str) {
+        let mut token = self.token.write().unwrap_or_else(|e| e.into_inner());
+        *token = (access_token.to_owned(), refresh_token.to_owned());
+    }
+
+    pub(crate) fn access_token(&self) -> String {
+        self.token.read().unwrap_or_else(|e| e.into_inner()).0.clone()
+    }
+
+    // generated clients are shared with the returned client, options are applied by the interceptor on every call
+    pub fn with_options(&self, options: CallOptions) -> Self {
+        Resource {
+            options: Arc::new(options),
+            ..self.clone()
+        }
+    }
+
+    pub async fn refresh(&self)
+        -> Result<(), Status>
+    {
+        let access_token = self.access_token();
+        self.refresh_from(&access_token).await
+    }
+
+    async fn refresh_from(&self, access_token: &str)
+        -> Result<(), Status>
+    {
+        let auth = self.auth.as_ref().ok_or(Status::failed_precondition(REFRESH_AUTH_EMPTY))?;
+        // only one clone refresh the token, others use the new token refreshed by that clone
+        let _guard = self.refresh_lock.lock().await;
+        let (current_access, refresh_token) = self.token();
+        if current_access != access_token {
+            return Ok(());
+        }
+        let response = auth.user_refresh(self.api_id, &current_access, &refresh_token).await?;
+        self.set_token(&response.access_token, &response.refresh_token);
+        Ok(())
+    }
+
+    // timeout in call options override the default timeout
+    fn call_timeout(&self, long: bool) -> Option<Duration> {
+        self.options.timeout.or(if long { self.long_timeout } else { self.timeout })
+    }
+
+    async fn call_with<T, F, Fut>(&self, timeout: Option<Duration>, resend: bool, f: F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        let access_token = self.access_token();
+        match with_call_options(&self.options, self.call_endpoint(timeout, resend, &f)).await {
+            // request scoped token is not refreshed since it is not owned by this client
+            Err(status) if status.code() == Code::Unauthenticated && self.auth.is_some() && self.options.token.is_none() => {
+                self.refresh_from(&access_token).await?;
+                with_call_options(&self.options, self.call_endpoint(timeout, resend, &f)).await
+            },
+            result => result
+        }
+    }
+
+    // balanced call fail immediately when no endpoint is healthy, and when the chosen endpoint is unavailable
+    // the endpoints are checked at once so the unhealthy one is ejected from the balance channel,
+    // only read is sent again since a mutation may be applied before the connection is lost
+    async fn call_endpoint<T, F, Fut>(&self, timeout: Option<Duration>, resend: bool, f: &F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        let balancer = match &self.balancer {
+            Some(balancer) => balancer,
+            None => return deadline(timeout, f()).await
+        };
+        if !balancer.is_available() {
+            return Err(Status::unavailable(ENDPOINT_UNAVAILABLE));
+        }
+        match deadline(timeout, f()).await {
+            Err(status) if status.code() == Code::Unavailable => {
+                let available = balancer.check().await;
+                if !resend {
+                    return Err(status);
+                }
+                if !available {
+                    return Err(Status::unavailable(ENDPOINT_UNAVAILABLE));
+                }
+                deadline(timeout, f()).await
+            },
+            result => result
+        }
+    }
+
+    // mutation is sent once
+    async fn call<T, F, Fut>(&self, f: F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        self.call_with(self.call_timeout(false), false, f).await
+    }
+
+    async fn call_retry<T, F, Fut>(&self, f: F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        let timeout = self.call_timeout(false);
+        self.retry.run(|| self.call_with(timeout, true, &f), |_| None).await
+    }
+
+    async fn call_long<T, F, Fut>(&self, f: F)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>
+    {
+        let timeout = self.call_timeout(true);
+        self.retry.run(|| self.call_with(timeout, true, &f), |_| None).await
+    }
+
+    // mutation is only retried when explicitly called with idempotency guard,
+    // failed over call is resent by the retry policy so the guard see the response of the resent call
+    pub async fn call_idempotent<T, F, Fut, G>(&self, f: F, guard: G)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>, G: Fn(&Status) -> Option<T>
+    {
+        self.retry.run(|| self.call(&f), guard).await
+    }
+
     pub async fn read_model(&self, id: Uuid)
         -> Result<ModelSchema, Status>
     {
-        model::read_model(&self, id)
+        self.call_retry(|| model::read_model(self, id))
         .await
         .map(|s| s.into())
     }
@@ -69,7 +319,7 @@ impl Resource {
     pub async fn list_model_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<ModelSchema>, Status>
     {
-        model::list_model_by_ids(&self, ids)
+        self.call_retry(|| model::list_model_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -77,7 +327,7 @@ impl Resource {
     pub async fn list_model_by_type(&self, type_id: Uuid)
         -> Result<Vec<ModelSchema>, Status>
     {
-        model::list_model_by_type(&self, type_id)
+        self.call_retry(|| model::list_model_by_type(self, type_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -85,7 +335,7 @@ impl Resource {
     pub async fn list_model_by_name(&self, name: &str)
         -> Result<Vec<ModelSchema>, Status>
     {
-        model::list_model_by_name(&self, name)
+        self.call_retry(|| model::list_model_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -93,7 +343,7 @@ impl Resource {
     pub async fn list_model_by_category(&self, category: &str)
         -> Result<Vec<ModelSchema>, Status>
     {
-        model::list_model_by_category(&self, category)
+        self.call_retry(|| model::list_model_by_category(self, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -101,7 +351,7 @@ impl Resource {
     pub async fn list_model_option(&self, type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>)
         -> Result<Vec<ModelSchema>, Status>
     {
-        model::list_model_option(&self, type_id, name, category)
+        self.call_retry(|| model::list_model_option(self, type_id, name, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -109,28 +359,28 @@ impl Resource {
     pub async fn create_model(&self, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        model::create_model(&self, id, data_type, category, name, description)
+        self.call(|| model::create_model(self, id, data_type, category, name, description))
         .await
     }
 
     pub async fn update_model(&self, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        model::update_model(&self, id, data_type, category, name, description)
+        self.call(|| model::update_model(self, id, data_type, category, name, description))
         .await
     }
 
     pub async fn delete_model(&self, id: Uuid)
         -> Result<(), Status>
     {
-        model::delete_model(&self, id)
+        self.call(|| model::delete_model(self, id))
         .await
     }
 
     pub async fn read_model_config(&self, id: i32)
         -> Result<ModelConfigSchema, Status>
     {
-        model::read_model_config(&self, id)
+        self.call_retry(|| model::read_model_config(self, id))
         .await
         .map(|s| s.into())
     }
@@ -138,7 +388,7 @@ impl Resource {
     pub async fn list_model_config_by_model(&self, model_id: Uuid)
         -> Result<Vec<ModelConfigSchema>, Status>
     {
-        model::list_model_config_by_model(&self, model_id)
+        self.call_retry(|| model::list_model_config_by_model(self, model_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -146,28 +396,28 @@ impl Resource {
     pub async fn create_model_config(&self, model_id: Uuid, index: i32, name: &str, value: DataValue, category: &str)
         -> Result<i32, Status>
     {
-        model::create_model_config(&self, model_id, index, name, value, category)
+        self.call(|| model::create_model_config(self, model_id, index, name, value.clone(), category))
         .await
     }
 
     pub async fn update_model_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
         -> Result<(), Status>
     {
-        model::update_model_config(&self, id, name, value, category)
+        self.call(|| model::update_model_config(self, id, name, value.clone(), category))
         .await
     }
 
     pub async fn delete_model_config(&self, id: i32)
         -> Result<(), Status>
     {
-        model::delete_model_config(&self, id)
+        self.call(|| model::delete_model_config(self, id))
         .await
     }
 
     pub async fn read_tag(&self, model_id: Uuid, tag: i16)
         -> Result<TagSchema, Status>
     {
-        model::read_tag(&self, model_id, tag)
+        self.call_retry(|| model::read_tag(self, model_id, tag))
         .await
         .map(|s| s.into())
     }
@@ -175,7 +425,7 @@ impl Resource {
     pub async fn list_tag_by_model(&self, model_id: Uuid)
         -> Result<Vec<TagSchema>, Status>
     {
-        model::list_tag_by_model(&self, model_id)
+        self.call_retry(|| model::list_tag_by_model(self, model_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -183,28 +433,28 @@ impl Resource {
     pub async fn create_tag(&self, model_id: Uuid, tag: i16, name: &str, members: &[i16])
         -> Result<(), Status>
     {
-        model::create_tag(&self, model_id, tag, name, members)
+        self.call(|| model::create_tag(self, model_id, tag, name, members))
         .await
     }
 
     pub async fn update_tag(&self, model_id: Uuid, tag: i16, name: Option<&str>, members: Option<&[i16]>)
         -> Result<(), Status>
     {
-        model::update_tag(&self, model_id, tag, name, members)
+        self.call(|| model::update_tag(self, model_id, tag, name, members))
         .await
     }
 
     pub async fn delete_tag(&self, model_id: Uuid, tag: i16)
         -> Result<(), Status>
     {
-        model::delete_tag(&self, model_id, tag)
+        self.call(|| model::delete_tag(self, model_id, tag))
         .await
     }
 
     pub async fn read_device(&self, id: Uuid)
         -> Result<DeviceSchema, Status>
     {
-        device::read_device(&self, id)
+        self.call_retry(|| device::read_device(self, id))
         .await
         .map(|s| s.into())
     }
@@ -212,7 +462,7 @@ impl Resource {
     pub async fn read_device_by_sn(&self, serial_number: &str)
         -> Result<DeviceSchema, Status>
     {
-        device::read_device_by_sn(&self, serial_number)
+        self.call_retry(|| device::read_device_by_sn(self, serial_number))
         .await
         .map(|s| s.into())
     }
@@ -220,7 +470,7 @@ impl Resource {
     pub async fn list_device_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<DeviceSchema>, Status>
     {
-        device::list_device_by_ids(&self, ids)
+        self.call_retry(|| device::list_device_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -228,7 +478,7 @@ impl Resource {
     pub async fn list_device_by_gateway(&self, gateway_id: Uuid)
         -> Result<Vec<DeviceSchema>, Status>
     {
-        device::list_device_by_gateway(&self, gateway_id)
+        self.call_retry(|| device::list_device_by_gateway(self, gateway_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -236,7 +486,7 @@ impl Resource {
     pub async fn list_device_by_type(&self, type_id: Uuid)
         -> Result<Vec<DeviceSchema>, Status>
     {
-        device::list_device_by_type(&self, type_id)
+        self.call_retry(|| device::list_device_by_type(self, type_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -244,7 +494,7 @@ impl Resource {
     pub async fn list_device_by_name(&self, name: &str)
         -> Result<Vec<DeviceSchema>, Status>
     {
-        device::list_device_by_name(&self, name)
+        self.call_retry(|| device::list_device_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -252,7 +502,7 @@ impl Resource {
     pub async fn list_device_option(&self, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<DeviceSchema>, Status>
     {
-        device::list_device_option(&self, gateway_id, type_id, name)
+        self.call_retry(|| device::list_device_option(self, gateway_id, type_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -260,28 +510,28 @@ impl Resource {
     pub async fn create_device(&self, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        device::create_device(&self, id, gateway_id, type_id, serial_number, name, description)
+        self.call(|| device::create_device(self, id, gateway_id, type_id, serial_number, name, description))
         .await
     }
 
     pub async fn update_device(&self, id: Uuid, gateway_id: Option<Uuid>, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        device::update_device(&self, id, gateway_id, type_id, serial_number, name, description)
+        self.call(|| device::update_device(self, id, gateway_id, type_id, serial_number, name, description))
         .await
     }
 
     pub async fn delete_device(&self, id: Uuid)
         -> Result<(), Status>
     {
-        device::delete_device(&self, id)
+        self.call(|| device::delete_device(self, id))
         .await
     }
 
     pub async fn read_gateway(&self, id: Uuid)
         -> Result<GatewaySchema, Status>
     {
-        device::read_gateway(&self, id)
+        self.call_retry(|| device::read_gateway(self, id))
         .await
         .map(|s| s.into())
     }
@@ -289,7 +539,7 @@ impl Resource {
     pub async fn read_gateway_by_sn(&self, serial_number: &str)
         -> Result<GatewaySchema, Status>
     {
-        device::read_gateway_by_sn(&self, serial_number)
+        self.call_retry(|| device::read_gateway_by_sn(self, serial_number))
         .await
         .map(|s| s.into())
     }
@@ -297,7 +547,7 @@ impl Resource {
     pub async fn list_gateway_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<GatewaySchema>, Status>
     {
-        device::list_gateway_by_ids(&self, ids)
+        self.call_retry(|| device::list_gateway_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -305,7 +555,7 @@ impl Resource {
     pub async fn list_gateway_by_type(&self, type_id: Uuid)
         -> Result<Vec<GatewaySchema>, Status>
     {
-        device::list_gateway_by_type(&self, type_id)
+        self.call_retry(|| device::list_gateway_by_type(self, type_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -313,7 +563,7 @@ impl Resource {
     pub async fn list_gateway_by_name(&self, name: &str)
         -> Result<Vec<GatewaySchema>, Status>
     {
-        device::list_gateway_by_name(&self, name)
+        self.call_retry(|| device::list_gateway_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -321,7 +571,7 @@ impl Resource {
     pub async fn list_gateway_option(&self, type_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<GatewaySchema>, Status>
     {
-        device::list_gateway_option(&self, type_id, name)
+        self.call_retry(|| device::list_gateway_option(self, type_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -329,28 +579,28 @@ impl Resource {
     pub async fn create_gateway(&self, id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        device::create_gateway(&self, id, type_id, serial_number, name, description)
+        self.call(|| device::create_gateway(self, id, type_id, serial_number, name, description))
         .await
     }
 
     pub async fn update_gateway(&self, id: Uuid, type_id: Option<Uuid>, serial_number: Option<&str>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        device::update_gateway(&self, id, type_id, serial_number, name, description)
+        self.call(|| device::update_gateway(self, id, type_id, serial_number, name, description))
         .await
     }
 
     pub async fn delete_gateway(&self, id: Uuid)
         -> Result<(), Status>
     {
-        device::delete_gateway(&self, id)
+        self.call(|| device::delete_gateway(self, id))
         .await
     }
 
     pub async fn read_device_config(&self, id: i32)
         -> Result<DeviceConfigSchema, Status>
     {
-        device::read_device_config(&self, id)
+        self.call_retry(|| device::read_device_config(self, id))
         .await
         .map(|s| s.into())
     }
@@ -358,7 +608,7 @@ impl Resource {
     pub async fn list_device_config_by_device(&self, device_id: Uuid)
         -> Result<Vec<DeviceConfigSchema>, Status>
     {
-        device::list_device_config_by_device(&self, device_id)
+        self.call_retry(|| device::list_device_config_by_device(self, device_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -366,28 +616,28 @@ impl Resource {
     pub async fn create_device_config(&self, device_id: Uuid, name: &str, value: DataValue, category: &str)
         -> Result<i32, Status>
     {
-        device::create_device_config(&self, device_id, name, value, category)
+        self.call(|| device::create_device_config(self, device_id, name, value.clone(), category))
         .await
     }
 
     pub async fn update_device_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
         -> Result<(), Status>
     {
-        device::update_device_config(&self, id, name, value, category)
+        self.call(|| device::update_device_config(self, id, name, value.clone(), category))
         .await
     }
 
     pub async fn delete_device_config(&self, id: i32)
         -> Result<(), Status>
     {
-        device::delete_device_config(&self, id)
+        self.call(|| device::delete_device_config(self, id))
         .await
     }
 
     pub async fn read_gateway_config(&self, id: i32)
         -> Result<GatewayConfigSchema, Status>
     {
-        device::read_gateway_config(&self, id)
+        self.call_retry(|| device::read_gateway_config(self, id))
         .await
         .map(|s| s.into())
     }
@@ -395,7 +645,7 @@ impl Resource {
     pub async fn list_gateway_config_by_gateway(&self, gateway_id: Uuid)
         -> Result<Vec<GatewayConfigSchema>, Status>
     {
-        device::list_gateway_config_by_gateway(&self, gateway_id)
+        self.call_retry(|| device::list_gateway_config_by_gateway(self, gateway_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -403,28 +653,28 @@ impl Resource {
     pub async fn create_gateway_config(&self, gateway_id: Uuid, name: &str, value: DataValue, category: &str)
         -> Result<i32, Status>
     {
-        device::create_gateway_config(&self, gateway_id, name, value, category)
+        self.call(|| device::create_gateway_config(self, gateway_id, name, value.clone(), category))
         .await
     }
 
     pub async fn update_gateway_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
         -> Result<(), Status>
     {
-        device::update_gateway_config(&self, id, name, value, category)
+        self.call(|| device::update_gateway_config(self, id, name, value.clone(), category))
         .await
     }
 
     pub async fn delete_gateway_config(&self, id: i32)
         -> Result<(), Status>
     {
-        device::delete_gateway_config(&self, id)
+        self.call(|| device::delete_gateway_config(self, id))
         .await
     }
 
     pub async fn read_type(&self, id: Uuid)
         -> Result<TypeSchema, Status>
     {
-        types::read_type(&self, id)
+        self.call_retry(|| types::read_type(self, id))
         .await
         .map(|s| s.into())
     }
@@ -432,7 +682,7 @@ impl Resource {
     pub async fn list_type_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<TypeSchema>, Status>
     {
-        types::list_type_by_ids(&self, ids)
+        self.call_retry(|| types::list_type_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -440,7 +690,7 @@ impl Resource {
     pub async fn list_type_by_name(&self, name: &str)
         -> Result<Vec<TypeSchema>, Status>
     {
-        types::list_type_by_name(&self, name)
+        self.call_retry(|| types::list_type_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -448,7 +698,7 @@ impl Resource {
     pub async fn list_type_option(&self, name: Option<&str>)
         -> Result<Vec<TypeSchema>, Status>
     {
-        types::list_type_option(&self, name)
+        self.call_retry(|| types::list_type_option(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -456,42 +706,42 @@ impl Resource {
     pub async fn create_type(&self, id: Uuid, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        types::create_type(&self, id, name, description)
+        self.call(|| types::create_type(self, id, name, description))
         .await
     }
 
     pub async fn update_type(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        types::update_type(&self, id, name, description)
+        self.call(|| types::update_type(self, id, name, description))
         .await
     }
 
     pub async fn delete_type(&self, id: Uuid)
         -> Result<(), Status>
     {
-        types::delete_type(&self, id)
+        self.call(|| types::delete_type(self, id))
         .await
     }
 
     pub async fn add_type_model(&self, id: Uuid, model_id: Uuid)
         -> Result<(), Status>
     {
-        types::add_type_model(&self, id, model_id)
+        self.call(|| types::add_type_model(self, id, model_id))
         .await
     }
 
     pub async fn remove_type_model(&self, id: Uuid, model_id: Uuid)
         -> Result<(), Status>
     {
-        types::remove_type_model(&self, id, model_id)
+        self.call(|| types::remove_type_model(self, id, model_id))
         .await
     }
 
     pub async fn read_group_model(&self, id: Uuid)
         -> Result<GroupModelSchema, Status>
     {
-        group::read_group_model(&self, id)
+        self.call_retry(|| group::read_group_model(self, id))
         .await
         .map(|s| s.into())
     }
@@ -499,7 +749,7 @@ impl Resource {
     pub async fn list_group_model_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<GroupModelSchema>, Status>
     {
-        group::list_group_model_by_ids(&self, ids)
+        self.call_retry(|| group::list_group_model_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -507,7 +757,7 @@ impl Resource {
     pub async fn list_group_model_by_name(&self, name: &str)
         -> Result<Vec<GroupModelSchema>, Status>
     {
-        group::list_group_model_by_name(&self, name)
+        self.call_retry(|| group::list_group_model_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -515,7 +765,7 @@ impl Resource {
     pub async fn list_group_model_by_category(&self, category: &str)
         -> Result<Vec<GroupModelSchema>, Status>
     {
-        group::list_group_model_by_category(&self, category)
+        self.call_retry(|| group::list_group_model_by_category(self, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -523,7 +773,7 @@ impl Resource {
     pub async fn list_group_model_option(&self, name: Option<&str>, category: Option<&str>)
         -> Result<Vec<GroupModelSchema>, Status>
     {
-        group::list_group_model_option(&self, name, category)
+        self.call_retry(|| group::list_group_model_option(self, name, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -531,42 +781,42 @@ impl Resource {
     pub async fn create_group_model(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        group::create_group_model(&self, id, name, category, description)
+        self.call(|| group::create_group_model(self, id, name, category, description))
         .await
     }
 
     pub async fn update_group_model(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        group::update_group_model(&self, id, name, category, description)
+        self.call(|| group::update_group_model(self, id, name, category, description))
         .await
     }
 
     pub async fn delete_group_model(&self, id: Uuid)
         -> Result<(), Status>
     {
-        group::delete_group_model(&self, id)
+        self.call(|| group::delete_group_model(self, id))
         .await
     }
 
     pub async fn add_group_model_member(&self, id: Uuid, model_id: Uuid)
         -> Result<(), Status>
     {
-        group::add_group_model_member(&self, id, model_id)
+        self.call(|| group::add_group_model_member(self, id, model_id))
         .await
     }
 
     pub async fn remove_group_model_member(&self, id: Uuid, model_id: Uuid)
         -> Result<(), Status>
     {
-        group::remove_group_model_member(&self, id, model_id)
+        self.call(|| group::remove_group_model_member(self, id, model_id))
         .await
     }
 
     pub async fn read_group_device(&self, id: Uuid)
         -> Result<GroupDeviceSchema, Status>
     {
-        group::read_group_device(&self, id)
+        self.call_retry(|| group::read_group_device(self, id))
         .await
         .map(|s| s.into())
     }
@@ -574,7 +824,7 @@ impl Resource {
     pub async fn list_group_device_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<GroupDeviceSchema>, Status>
     {
-        group::list_group_device_by_ids(&self, ids)
+        self.call_retry(|| group::list_group_device_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -582,7 +832,7 @@ impl Resource {
     pub async fn list_group_device_by_name(&self, name: &str)
         -> Result<Vec<GroupDeviceSchema>, Status>
     {
-        group::list_group_device_by_name(&self, name)
+        self.call_retry(|| group::list_group_device_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -590,7 +840,7 @@ impl Resource {
     pub async fn list_group_device_by_category(&self, category: &str)
         -> Result<Vec<GroupDeviceSchema>, Status>
     {
-        group::list_group_device_by_category(&self, category)
+        self.call_retry(|| group::list_group_device_by_category(self, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -598,7 +848,7 @@ impl Resource {
     pub async fn list_group_device_option(&self, name: Option<&str>, category: Option<&str>)
         -> Result<Vec<GroupDeviceSchema>, Status>
     {
-        group::list_group_device_option(&self, name, category)
+        self.call_retry(|| group::list_group_device_option(self, name, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -606,42 +856,42 @@ impl Resource {
     pub async fn create_group_device(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        group::create_group_device(&self, id, name, category, description)
+        self.call(|| group::create_group_device(self, id, name, category, description))
         .await
     }
 
     pub async fn update_group_device(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        group::update_group_device(&self, id, name, category, description)
+        self.call(|| group::update_group_device(self, id, name, category, description))
         .await
     }
 
     pub async fn delete_group_device(&self, id: Uuid)
         -> Result<(), Status>
     {
-        group::delete_group_device(&self, id)
+        self.call(|| group::delete_group_device(self, id))
         .await
     }
 
     pub async fn add_group_device_member(&self, id: Uuid, device_id: Uuid)
         -> Result<(), Status>
     {
-        group::add_group_device_member(&self, id, device_id)
+        self.call(|| group::add_group_device_member(self, id, device_id))
         .await
     }
 
     pub async fn remove_group_device_member(&self, id: Uuid, device_id: Uuid)
         -> Result<(), Status>
     {
-        group::remove_group_device_member(&self, id, device_id)
+        self.call(|| group::remove_group_device_member(self, id, device_id))
         .await
     }
 
     pub async fn read_group_gateway(&self, id: Uuid)
         -> Result<GroupGatewaySchema, Status>
     {
-        group::read_group_gateway(&self, id)
+        self.call_retry(|| group::read_group_gateway(self, id))
         .await
         .map(|s| s.into())
     }
@@ -649,7 +899,7 @@ impl Resource {
     pub async fn list_group_gateway_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<GroupGatewaySchema>, Status>
     {
-        group::list_group_gateway_by_ids(&self, ids)
+        self.call_retry(|| group::list_group_gateway_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -657,7 +907,7 @@ impl Resource {
     pub async fn list_group_gateway_by_name(&self, name: &str)
         -> Result<Vec<GroupGatewaySchema>, Status>
     {
-        group::list_group_gateway_by_name(&self, name)
+        self.call_retry(|| group::list_group_gateway_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -665,7 +915,7 @@ impl Resource {
     pub async fn list_group_gateway_by_category(&self, category: &str)
         -> Result<Vec<GroupGatewaySchema>, Status>
     {
-        group::list_group_gateway_by_category(&self, category)
+        self.call_retry(|| group::list_group_gateway_by_category(self, category))
         .await
         .map(|v| {
             v.into_iter().map(|s| s.into()).collect()
@@ -675,7 +925,7 @@ impl Resource {
     pub async fn list_group_gateway_option(&self, name: Option<&str>, category: Option<&str>)
         -> Result<Vec<GroupGatewaySchema>, Status>
     {
-        group::list_group_gateway_option(&self, name, category)
+        self.call_retry(|| group::list_group_gateway_option(self, name, category))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -683,42 +933,42 @@ impl Resource {
     pub async fn create_group_gateway(&self, id: Uuid, name: &str, category: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        group::create_group_gateway(&self, id, name, category, description)
+        self.call(|| group::create_group_gateway(self, id, name, category, description))
         .await
     }
 
     pub async fn update_group_gateway(&self, id: Uuid, name: Option<&str>, category: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        group::update_group_gateway(&self, id, name, category, description)
+        self.call(|| group::update_group_gateway(self, id, name, category, description))
         .await
     }
 
     pub async fn delete_group_gateway(&self, id: Uuid)
         -> Result<(), Status>
     {
-        group::delete_group_gateway(&self, id)
+        self.call(|| group::delete_group_gateway(self, id))
         .await
     }
 
     pub async fn add_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
         -> Result<(), Status>
     {
-        group::add_group_gateway_member(&self, id, gateway_id)
+        self.call(|| group::add_group_gateway_member(self, id, gateway_id))
         .await
     }
 
     pub async fn remove_group_gateway_member(&self, id: Uuid, gateway_id: Uuid)
         -> Result<(), Status>
     {
-        group::remove_group_gateway_member(&self, id, gateway_id)
+        self.call(|| group::remove_group_gateway_member(self, id, gateway_id))
         .await
     }
 
     pub async fn read_set(&self, id: Uuid)
         -> Result<SetSchema, Status>
     {
-        set::read_set(&self, id)
+        self.call_retry(|| set::read_set(self, id))
         .await
         .map(|s| s.into())
     }
@@ -726,7 +976,7 @@ impl Resource {
     pub async fn list_set_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<SetSchema>, Status>
     {
-        set::list_set_by_ids(&self, ids)
+        self.call_retry(|| set::list_set_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -734,7 +984,7 @@ impl Resource {
     pub async fn list_set_by_template(&self, template_id: Uuid)
         -> Result<Vec<SetSchema>, Status>
     {
-        set::list_set_by_template(&self, template_id)
+        self.call_retry(|| set::list_set_by_template(self, template_id))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -742,7 +992,7 @@ impl Resource {
     pub async fn list_set_by_name(&self, name: &str)
         -> Result<Vec<SetSchema>, Status>
     {
-        set::list_set_by_name(&self, name)
+        self.call_retry(|| set::list_set_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -750,7 +1000,7 @@ impl Resource {
     pub async fn list_set_option(&self, template_id: Option<Uuid>, name: Option<&str>)
         -> Result<Vec<SetSchema>, Status>
     {
-        set::list_set_option(&self, template_id, name)
+        self.call_retry(|| set::list_set_option(self, template_id, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -758,49 +1008,49 @@ impl Resource {
     pub async fn create_set(&self, id: Uuid, template_id: Uuid, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        set::create_set(&self, id, template_id, name, description)
+        self.call(|| set::create_set(self, id, template_id, name, description))
         .await
     }
 
     pub async fn update_set(&self, id: Uuid, template_id: Option<Uuid>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        set::update_set(&self, id, template_id, name, description)
+        self.call(|| set::update_set(self, id, template_id, name, description))
         .await
     }
 
     pub async fn delete_set(&self, id: Uuid)
         -> Result<(), Status>
     {
-        set::delete_set(&self, id)
+        self.call(|| set::delete_set(self, id))
         .await
     }
 
     pub async fn add_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
         -> Result<(), Status>
     {
-        set::add_set_member(&self, id, device_id, model_id, data_index)
+        self.call(|| set::add_set_member(self, id, device_id, model_id, data_index))
         .await
     }
 
     pub async fn remove_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid)
         -> Result<(), Status>
     {
-        set::remove_set_member(&self, id, device_id, model_id)
+        self.call(|| set::remove_set_member(self, id, device_id, model_id))
         .await
     }
 
     pub async fn swap_set_member(&self, id: Uuid, device_id_1: Uuid, model_id_1: Uuid, device_id_2: Uuid, model_id_2: Uuid)
         -> Result<(), Status>
     {
-        set::swap_set_member(&self, id, device_id_1, model_id_1, device_id_2, model_id_2)
+        self.call(|| set::swap_set_member(self, id, device_id_1, model_id_1, device_id_2, model_id_2))
         .await
     }
 
     pub async fn read_set_template(&self, id: Uuid)
         -> Result<SetTemplateSchema, Status>
     {
-        set::read_set_template(&self, id)
+        self.call_retry(|| set::read_set_template(self, id))
         .await
         .map(|s| s.into())
     }
@@ -808,7 +1058,7 @@ impl Resource {
     pub async fn list_set_template_by_ids(&self, ids: &[Uuid])
         -> Result<Vec<SetTemplateSchema>, Status>
     {
-        set::list_set_template_by_ids(&self, ids)
+        self.call_retry(|| set::list_set_template_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -816,7 +1066,7 @@ impl Resource {
     pub async fn list_set_template_by_name(&self, name: &str)
         -> Result<Vec<SetTemplateSchema>, Status>
     {
-        set::list_set_template_by_name(&self, name)
+        self.call_retry(|| set::list_set_template_by_name(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -824,7 +1074,7 @@ impl Resource {
     pub async fn list_set_template_option(&self, name: Option<&str>)
         -> Result<Vec<SetTemplateSchema>, Status>
     {
-        set::list_set_template_option(&self, name)
+        self.call_retry(|| set::list_set_template_option(self, name))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -832,56 +1082,56 @@ impl Resource {
     pub async fn create_set_template(&self, id: Uuid, name: &str, description: Option<&str>)
         -> Result<Uuid, Status>
     {
-        set::create_set_template(&self, id, name, description)
+        self.call(|| set::create_set_template(self, id, name, description))
         .await
     }
 
     pub async fn update_set_template(&self, id: Uuid, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        set::update_set_template(&self, id, name, description)
+        self.call(|| set::update_set_template(self, id, name, description))
         .await
     }
 
     pub async fn delete_set_template(&self, id: Uuid)
         -> Result<(), Status>
     {
-        set::delete_set_template(&self, id)
+        self.call(|| set::delete_set_template(self, id))
         .await
     }
 
     pub async fn add_set_template_member(&self, id: Uuid, type_id: Uuid, model_id: Uuid, data_index: &[u8])
         -> Result<(), Status>
     {
-        set::add_set_template_member(&self, id, type_id, model_id, data_index)
+        self.call(|| set::add_set_template_member(self, id, type_id, model_id, data_index))
         .await
     }
 
     pub async fn remove_set_template_member(&self, id: Uuid, index: usize)
         -> Result<(), Status>
     {
-        set::remove_set_template_member(&self, id, index)
+        self.call(|| set::remove_set_template_member(self, id, index))
         .await
     }
 
     pub async fn swap_set_template_member(&self, id: Uuid, index_1: usize, index_2: usize)
         -> Result<(), Status>
     {
-        set::swap_set_template_member(&self, id, index_1, index_2)
+        self.call(|| set::swap_set_template_member(self, id, index_1, index_2))
         .await
     }
 
     pub async fn read_slice(&self, id: i32)
         -> Result<SliceSchema, Status>
     {
-        slice::read_slice(&self, id).await
+        self.call_retry(|| slice::read_slice(self, id)).await
         .map(|s| s.into())
     }
 
     pub async fn list_slice_by_ids(&self, ids: &[i32])
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_by_ids(&self, ids).await
+        self.call_retry(|| slice::list_slice_by_ids(self, ids)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
 
     }
@@ -889,90 +1139,90 @@ impl Resource {
     pub async fn list_slice_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_by_time(&self, device_id, model_id, timestamp).await
+        self.call_retry(|| slice::list_slice_by_time(self, device_id, model_id, timestamp)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_by_range(&self, device_id, model_id, begin, end).await
+        self.call_retry(|| slice::list_slice_by_range(self, device_id, model_id, begin, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_by_name_time(&self, name, timestamp).await
+        self.call_retry(|| slice::list_slice_by_name_time(self, name, timestamp)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_by_name_range(&self, name, begin, end).await
+        self.call_retry(|| slice::list_slice_by_name_range(self, name, begin, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_option(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_option(&self, device_id, model_id, name, begin_or_timestamp, end).await
+        self.call_retry(|| slice::list_slice_option(self, device_id, model_id, name, begin_or_timestamp, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_group_by_time(&self, device_ids, model_ids, timestamp).await
+        self.call_retry(|| slice::list_slice_group_by_time(self, device_ids, model_ids, timestamp)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_group_by_range(&self, device_ids, model_ids, begin, end).await
+        self.call_retry(|| slice::list_slice_group_by_range(self, device_ids, model_ids, begin, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_group_option(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
         -> Result<Vec<SliceSchema>, Status>
     {
-        slice::list_slice_group_option(&self, device_ids, model_ids, name, begin_or_timestamp, end).await
+        self.call_retry(|| slice::list_slice_group_option(self, device_ids, model_ids, name, begin_or_timestamp, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn create_slice(&self, device_id: Uuid, model_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
         -> Result<i32, Status>
     {
-        slice::create_slice(&self, device_id, model_id, timestamp_begin, timestamp_end, name, description)
+        self.call(|| slice::create_slice(self, device_id, model_id, timestamp_begin, timestamp_end, name, description))
         .await
     }
 
     pub async fn update_slice(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        slice::update_slice(&self, id, timestamp_begin, timestamp_end, name, description)
+        self.call(|| slice::update_slice(self, id, timestamp_begin, timestamp_end, name, description))
         .await
     }
 
     pub async fn delete_slice(&self, id: i32)
         -> Result<(), Status>
     {
-        slice::delete_slice(&self, id).await
+        self.call(|| slice::delete_slice(self, id)).await
     }
 
     pub async fn read_slice_set(&self, id: i32)
         -> Result<SliceSetSchema, Status>
     {
-        slice::read_slice_set(&self, id).await
+        self.call_retry(|| slice::read_slice_set(self, id)).await
         .map(|s| s.into())
     }
 
     pub async fn list_slice_set_by_ids(&self, ids: &[i32])
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_by_ids(&self, ids).await
+        self.call_retry(|| slice::list_slice_set_by_ids(self, ids)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
 
     }
@@ -980,62 +1230,62 @@ impl Resource {
     pub async fn list_slice_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_by_time(&self, set_id, timestamp).await
+        self.call_retry(|| slice::list_slice_set_by_time(self, set_id, timestamp)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_by_range(&self, set_id, begin, end).await
+        self.call_retry(|| slice::list_slice_set_by_range(self, set_id, begin, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_set_by_name_time(&self, name: &str, timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_by_name_time(&self, name, timestamp).await
+        self.call_retry(|| slice::list_slice_set_by_name_time(self, name, timestamp)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_set_by_name_range(&self, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_by_name_range(&self, name, begin, end).await
+        self.call_retry(|| slice::list_slice_set_by_name_range(self, name, begin, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn list_slice_set_option(&self, set_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
         -> Result<Vec<SliceSetSchema>, Status>
     {
-        slice::list_slice_set_option(&self, set_id, name, begin_or_timestamp, end).await
+        self.call_retry(|| slice::list_slice_set_option(self, set_id, name, begin_or_timestamp, end)).await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
     pub async fn create_slice_set(&self, set_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
         -> Result<i32, Status>
     {
-        slice::create_slice_set(&self, set_id, timestamp_begin, timestamp_end, name, description)
+        self.call(|| slice::create_slice_set(self, set_id, timestamp_begin, timestamp_end, name, description))
         .await
     }
 
     pub async fn update_slice_set(&self, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
         -> Result<(), Status>
     {
-        slice::update_slice_set(&self, id, timestamp_begin, timestamp_end, name, description)
+        self.call(|| slice::update_slice_set(self, id, timestamp_begin, timestamp_end, name, description))
         .await
     }
 
     pub async fn delete_slice_set(&self, id: i32)
         -> Result<(), Status>
     {
-        slice::delete_slice_set(&self, id).await
+        self.call(|| slice::delete_slice_set(self, id)).await
     }
 
     pub async fn read_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DataSchema, Status>
     {
-        data::read_data(&self, device_id, model_id, timestamp, tag)
+        self.call_retry(|| data::read_data(self, device_id, model_id, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1043,7 +1293,7 @@ impl Resource {
     pub async fn list_data_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_by_time(&self, device_id, model_id, timestamp, tag)
+        self.call_long(|| data::list_data_by_time(self, device_id, model_id, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1051,7 +1301,7 @@ impl Resource {
     pub async fn list_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| data::list_data_by_latest(self, device_id, model_id, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1059,7 +1309,7 @@ impl Resource {
     pub async fn list_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| data::list_data_by_range(self, device_id, model_id, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1067,7 +1317,7 @@ impl Resource {
     pub async fn list_data_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_by_number_before(&self, device_id, model_id, before, number, tag)
+        self.call_long(|| data::list_data_by_number_before(self, device_id, model_id, before, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1075,7 +1325,7 @@ impl Resource {
     pub async fn list_data_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_by_number_after(&self, device_id, model_id, after, number, tag)
+        self.call_long(|| data::list_data_by_number_after(self, device_id, model_id, after, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1083,7 +1333,7 @@ impl Resource {
     pub async fn list_data_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_group_by_time(&self, device_ids, model_ids, timestamp, tag)
+        self.call_long(|| data::list_data_group_by_time(self, device_ids, model_ids, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1091,7 +1341,7 @@ impl Resource {
     pub async fn list_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_group_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| data::list_data_group_by_latest(self, device_ids, model_ids, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1099,7 +1349,7 @@ impl Resource {
     pub async fn list_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_group_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| data::list_data_group_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1107,7 +1357,7 @@ impl Resource {
     pub async fn list_data_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_group_by_number_before(&self, device_ids, model_ids, before, number, tag)
+        self.call_long(|| data::list_data_group_by_number_before(self, device_ids, model_ids, before, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1115,7 +1365,7 @@ impl Resource {
     pub async fn list_data_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
-        data::list_data_group_by_number_after(&self, device_ids, model_ids, after, number, tag)
+        self.call_long(|| data::list_data_group_by_number_after(self, device_ids, model_ids, after, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1123,7 +1373,7 @@ impl Resource {
     pub async fn read_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DataSetSchema, Status>
     {
-        data::read_data_set(&self, set_id, timestamp, tag)
+        self.call_retry(|| data::read_data_set(self, set_id, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1131,7 +1381,7 @@ impl Resource {
     pub async fn list_data_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSetSchema>, Status>
     {
-        data::list_data_set_by_time(&self, set_id, timestamp, tag)
+        self.call_long(|| data::list_data_set_by_time(self, set_id, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1139,7 +1389,7 @@ impl Resource {
     pub async fn list_data_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSetSchema>, Status>
     {
-        data::list_data_set_by_latest(&self, set_id, latest, tag)
+        self.call_long(|| data::list_data_set_by_latest(self, set_id, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1147,7 +1397,7 @@ impl Resource {
     pub async fn list_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DataSetSchema>, Status>
     {
-        data::list_data_set_by_range(&self, set_id, begin, end, tag)
+        self.call_long(|| data::list_data_set_by_range(self, set_id, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1155,28 +1405,28 @@ impl Resource {
     pub async fn create_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
         -> Result<(), Status>
     {
-        data::create_data(&self, device_id, model_id, timestamp, data, tag)
+        self.call(|| data::create_data(self, device_id, model_id, timestamp, data, tag))
         .await
     }
 
     pub async fn create_data_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
         -> Result<(), Status>
     {
-        data::create_data_multiple(&self, device_ids, model_ids, timestamps, data, tags)
+        self.call(|| data::create_data_multiple(self, device_ids, model_ids, timestamps, data, tags))
         .await
     }
 
     pub async fn delete_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        data::delete_data(&self, device_id, model_id, timestamp, tag)
+        self.call(|| data::delete_data(self, device_id, model_id, timestamp, tag))
         .await
     }
 
     pub async fn read_data_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
-        data::read_data_timestamp(&self, device_id, model_id, timestamp, tag)
+        self.call_retry(|| data::read_data_timestamp(self, device_id, model_id, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1184,21 +1434,21 @@ impl Resource {
     pub async fn list_data_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        data::list_data_timestamp_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| data::list_data_timestamp_by_latest(self, device_id, model_id, latest, tag))
         .await
     }
 
     pub async fn list_data_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        data::list_data_timestamp_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| data::list_data_timestamp_by_range(self, device_id, model_id, begin, end, tag))
         .await
     }
 
     pub async fn read_data_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
-        data::read_data_group_timestamp(&self, device_ids, model_ids, timestamp, tag)
+        self.call_retry(|| data::read_data_group_timestamp(self, device_ids, model_ids, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1206,63 +1456,63 @@ impl Resource {
     pub async fn list_data_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        data::list_data_group_timestamp_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| data::list_data_group_timestamp_by_latest(self, device_ids, model_ids, latest, tag))
         .await
     }
 
     pub async fn list_data_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        data::list_data_group_timestamp_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| data::list_data_group_timestamp_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
     }
 
     pub async fn count_data(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data(&self, device_id, model_id, tag)
+        self.call_long(|| data::count_data(self, device_id, model_id, tag))
         .await
     }
 
     pub async fn count_data_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| data::count_data_by_latest(self, device_id, model_id, latest, tag))
         .await
     }
 
     pub async fn count_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| data::count_data_by_range(self, device_id, model_id, begin, end, tag))
         .await
     }
 
     pub async fn count_data_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data_group(&self, device_ids, model_ids, tag)
+        self.call_long(|| data::count_data_group(self, device_ids, model_ids, tag))
         .await
     }
 
     pub async fn count_data_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data_group_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| data::count_data_group_by_latest(self, device_ids, model_ids, latest, tag))
         .await
     }
 
     pub async fn count_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        data::count_data_group_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| data::count_data_group_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
     }
 
     pub async fn read_buffer(&self, id: i32)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer(&self, id)
+        self.call_retry(|| buffer::read_buffer(self, id))
         .await
         .map(|s| s.into())
     }
@@ -1270,7 +1520,7 @@ impl Resource {
     pub async fn read_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer_by_time(&self, device_id, model_id, timestamp, tag)
+        self.call_retry(|| buffer::read_buffer_by_time(self, device_id, model_id, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1278,7 +1528,7 @@ impl Resource {
     pub async fn list_buffer_by_ids(&self, ids: &[i32])
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_ids(&self, ids)
+        self.call_long(|| buffer::list_buffer_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1286,7 +1536,7 @@ impl Resource {
     pub async fn list_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_time(&self, device_id, model_id, timestamp, tag)
+        self.call_long(|| buffer::list_buffer_by_time(self, device_id, model_id, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1294,7 +1544,7 @@ impl Resource {
     pub async fn list_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| buffer::list_buffer_by_latest(self, device_id, model_id, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1302,7 +1552,7 @@ impl Resource {
     pub async fn list_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| buffer::list_buffer_by_range(self, device_id, model_id, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1310,7 +1560,7 @@ impl Resource {
     pub async fn list_buffer_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_number_before(&self, device_id, model_id, before, number, tag)
+        self.call_long(|| buffer::list_buffer_by_number_before(self, device_id, model_id, before, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1318,7 +1568,7 @@ impl Resource {
     pub async fn list_buffer_by_number_after(&self, device_id: Uuid, model_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_by_number_after(&self, device_id, model_id, after, number, tag)
+        self.call_long(|| buffer::list_buffer_by_number_after(self, device_id, model_id, after, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1326,7 +1576,7 @@ impl Resource {
     pub async fn read_buffer_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer_first(&self, device_id, model_id, tag)
+        self.call_retry(|| buffer::read_buffer_first(self, device_id, model_id, tag))
         .await
         .map(|s| s.into())
     }
@@ -1334,7 +1584,7 @@ impl Resource {
     pub async fn read_buffer_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer_last(&self, device_id, model_id, tag)
+        self.call_retry(|| buffer::read_buffer_last(self, device_id, model_id, tag))
         .await
         .map(|s| s.into())
     }
@@ -1342,7 +1592,7 @@ impl Resource {
     pub async fn list_buffer_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_first(&self, number, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_first(self, number, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1350,7 +1600,7 @@ impl Resource {
     pub async fn list_buffer_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_first_offset(&self, number, offset, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_first_offset(self, number, offset, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1358,7 +1608,7 @@ impl Resource {
     pub async fn list_buffer_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_last(&self, number, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_last(self, number, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1366,7 +1616,7 @@ impl Resource {
     pub async fn list_buffer_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_last_offset(&self, number, offset, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_last_offset(self, number, offset, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1374,7 +1624,7 @@ impl Resource {
     pub async fn list_buffer_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_by_time(&self, device_ids, model_ids, timestamp, tag)
+        self.call_long(|| buffer::list_buffer_group_by_time(self, device_ids, model_ids, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1382,7 +1632,7 @@ impl Resource {
     pub async fn list_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| buffer::list_buffer_group_by_latest(self, device_ids, model_ids, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1390,7 +1640,7 @@ impl Resource {
     pub async fn list_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| buffer::list_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1398,7 +1648,7 @@ impl Resource {
     pub async fn list_buffer_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_by_number_before(&self, device_ids, model_ids, before, number, tag)
+        self.call_long(|| buffer::list_buffer_group_by_number_before(self, device_ids, model_ids, before, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1406,7 +1656,7 @@ impl Resource {
     pub async fn list_buffer_group_by_number_after(&self, device_ids: &[Uuid], model_ids: &[Uuid], after: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_by_number_after(&self, device_ids, model_ids, after, number, tag)
+        self.call_long(|| buffer::list_buffer_group_by_number_after(self, device_ids, model_ids, after, number, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1414,7 +1664,7 @@ impl Resource {
     pub async fn read_buffer_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer_group_first(&self, device_ids, model_ids, tag)
+        self.call_retry(|| buffer::read_buffer_group_first(self, device_ids, model_ids, tag))
         .await
         .map(|s| s.into())
     }
@@ -1422,7 +1672,7 @@ impl Resource {
     pub async fn read_buffer_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
-        buffer::read_buffer_group_last(&self, device_ids, model_ids, tag)
+        self.call_retry(|| buffer::read_buffer_group_last(self, device_ids, model_ids, tag))
         .await
         .map(|s| s.into())
     }
@@ -1430,7 +1680,7 @@ impl Resource {
     pub async fn list_buffer_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_first(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_first(self, number, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1438,7 +1688,7 @@ impl Resource {
     pub async fn list_buffer_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_first_offset(&self, number, offset, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_first_offset(self, number, offset, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1446,7 +1696,7 @@ impl Resource {
     pub async fn list_buffer_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_last(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_last(self, number, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1454,7 +1704,7 @@ impl Resource {
     pub async fn list_buffer_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
-        buffer::list_buffer_group_last_offset(&self, number, offset, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_last_offset(self, number, offset, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1462,7 +1712,7 @@ impl Resource {
     pub async fn read_buffer_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<BufferSetSchema, Status>
     {
-        buffer::read_buffer_set(&self, set_id, timestamp, tag)
+        self.call_retry(|| buffer::read_buffer_set(self, set_id, timestamp, tag))
         .await
         .map(|s| s.into())
     }
@@ -1470,7 +1720,7 @@ impl Resource {
     pub async fn list_buffer_set_by_time(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSetSchema>, Status>
     {
-        buffer::list_buffer_set_by_time(&self, set_id, timestamp, tag)
+        self.call_long(|| buffer::list_buffer_set_by_time(self, set_id, timestamp, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1478,7 +1728,7 @@ impl Resource {
     pub async fn list_buffer_set_by_latest(&self, set_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSetSchema>, Status>
     {
-        buffer::list_buffer_set_by_latest(&self, set_id, latest, tag)
+        self.call_long(|| buffer::list_buffer_set_by_latest(self, set_id, latest, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1486,7 +1736,7 @@ impl Resource {
     pub async fn list_buffer_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<BufferSetSchema>, Status>
     {
-        buffer::list_buffer_set_by_range(&self, set_id, begin, end, tag)
+        self.call_long(|| buffer::list_buffer_set_by_range(self, set_id, begin, end, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1494,160 +1744,160 @@ impl Resource {
     pub async fn create_buffer(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
         -> Result<i32, Status>
     {
-        buffer::create_buffer(&self, device_id, model_id, timestamp, data, tag)
+        self.call(|| buffer::create_buffer(self, device_id, model_id, timestamp, data, tag))
         .await
     }
 
     pub async fn create_buffer_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
         -> Result<Vec<i32>, Status>
     {
-        buffer::create_buffer_multiple(&self, device_ids, model_ids, timestamps, data, tags)
+        self.call(|| buffer::create_buffer_multiple(self, device_ids, model_ids, timestamps, data, tags))
         .await
     }
 
     pub async fn update_buffer(&self, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        buffer::update_buffer(&self, id, data, tag.map(|s| s.into()))
+        self.call(|| buffer::update_buffer(self, id, data, tag.map(|s| s.into())))
         .await
     }
 
     pub async fn update_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        buffer::update_buffer_by_time(&self, device_id, model_id, timestamp, data, tag)
+        self.call(|| buffer::update_buffer_by_time(self, device_id, model_id, timestamp, data, tag))
         .await
     }
 
     pub async fn delete_buffer(&self, id: i32)
         -> Result<(), Status>
     {
-        buffer::delete_buffer(&self, id)
+        self.call(|| buffer::delete_buffer(self, id))
         .await
     }
 
     pub async fn delete_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        buffer::delete_buffer_by_time(&self, device_id, model_id, timestamp, tag).await
+        self.call(|| buffer::delete_buffer_by_time(self, device_id, model_id, timestamp, tag)).await
     }
 
     pub async fn read_buffer_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
-        buffer::read_buffer_timestamp(&self, device_id, model_id, timestamp, tag)
+        self.call_retry(|| buffer::read_buffer_timestamp(self, device_id, model_id, timestamp, tag))
         .await
     }
 
     pub async fn list_buffer_timestamp_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_timestamp_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| buffer::list_buffer_timestamp_by_latest(self, device_id, model_id, latest, tag))
         .await
     }
 
     pub async fn list_buffer_timestamp_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_timestamp_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| buffer::list_buffer_timestamp_by_range(self, device_id, model_id, begin, end, tag))
         .await
     }
 
     pub async fn list_buffer_timestamp_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_timestamp_first(&self, number, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_timestamp_first(self, number, device_id, model_id, tag))
         .await
     }
 
     pub async fn list_buffer_timestamp_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_timestamp_last(&self, number, device_id, model_id, tag)
+        self.call_long(|| buffer::list_buffer_timestamp_last(self, number, device_id, model_id, tag))
         .await
     }
 
     pub async fn read_buffer_group_timestamp(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
-        buffer::read_buffer_group_timestamp(&self, device_ids, model_ids, timestamp, tag)
+        self.call_retry(|| buffer::read_buffer_group_timestamp(self, device_ids, model_ids, timestamp, tag))
         .await
     }
 
     pub async fn list_buffer_group_timestamp_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_group_timestamp_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| buffer::list_buffer_group_timestamp_by_latest(self, device_ids, model_ids, latest, tag))
         .await
     }
 
     pub async fn list_buffer_group_timestamp_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_group_timestamp_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| buffer::list_buffer_group_timestamp_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
     }
 
     pub async fn list_buffer_group_timestamp_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_group_timestamp_first(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_timestamp_first(self, number, device_ids, model_ids, tag))
         .await
     }
 
     pub async fn list_buffer_group_timestamp_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<DateTime<Utc>>, Status>
     {
-        buffer::list_buffer_group_timestamp_last(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| buffer::list_buffer_group_timestamp_last(self, number, device_ids, model_ids, tag))
         .await
     }
 
     pub async fn count_buffer(&self, device_id: Uuid, model_id: Uuid, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer(&self, device_id, model_id, tag)
+        self.call_long(|| buffer::count_buffer(self, device_id, model_id, tag))
         .await
     }
 
     pub async fn count_buffer_by_latest(&self, device_id: Uuid, model_id: Uuid, latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer_by_latest(&self, device_id, model_id, latest, tag)
+        self.call_long(|| buffer::count_buffer_by_latest(self, device_id, model_id, latest, tag))
         .await
     }
 
     pub async fn count_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer_by_range(&self, device_id, model_id, begin, end, tag)
+        self.call_long(|| buffer::count_buffer_by_range(self, device_id, model_id, begin, end, tag))
         .await
     }
 
     pub async fn count_buffer_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer_group(&self, device_ids, model_ids, tag)
+        self.call_long(|| buffer::count_buffer_group(self, device_ids, model_ids, tag))
         .await
     }
 
     pub async fn count_buffer_group_by_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], latest: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer_group_by_latest(&self, device_ids, model_ids, latest, tag)
+        self.call_long(|| buffer::count_buffer_group_by_latest(self, device_ids, model_ids, latest, tag))
         .await
     }
 
     pub async fn count_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
         -> Result<usize, Status>
     {
-        buffer::count_buffer_group_by_range(&self, device_ids, model_ids, begin, end, tag)
+        self.call_long(|| buffer::count_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag))
         .await
     }
 
     pub async fn read_log(&self, id: i32)
         -> Result<LogSchema, Status>
     {
-        log::read_log(&self, id)
+        self.call_retry(|| log::read_log(self, id))
         .await
         .map(|s| s.into())
     }
@@ -1655,7 +1905,7 @@ impl Resource {
     pub async fn read_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<LogSchema, Status>
     {
-        log::read_log_by_time(&self, timestamp, device_id, model_id, tag)
+        self.call_retry(|| log::read_log_by_time(self, timestamp, device_id, model_id, tag))
         .await
         .map(|s| s.into())
     }
@@ -1663,7 +1913,7 @@ impl Resource {
     pub async fn list_log_by_ids(&self, ids: &[i32])
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_by_ids(&self, ids)
+        self.call_long(|| log::list_log_by_ids(self, ids))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1671,7 +1921,7 @@ impl Resource {
     pub async fn list_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_by_time(&self, timestamp, device_id, model_id, tag)
+        self.call_long(|| log::list_log_by_time(self, timestamp, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1679,7 +1929,7 @@ impl Resource {
     pub async fn list_log_by_latest(&self, latest: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_by_latest(&self, latest, device_id, model_id, tag)
+        self.call_long(|| log::list_log_by_latest(self, latest, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1687,7 +1937,7 @@ impl Resource {
     pub async fn list_log_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_by_range(&self, begin, end, device_id, model_id, tag)
+        self.call_long(|| log::list_log_by_range(self, begin, end, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1695,7 +1945,7 @@ impl Resource {
     pub async fn read_log_first(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<LogSchema, Status>
     {
-        log::read_log_first(&self, device_id, model_id, tag)
+        self.call_retry(|| log::read_log_first(self, device_id, model_id, tag))
         .await
         .map(|v| v.into())
     }
@@ -1703,7 +1953,7 @@ impl Resource {
     pub async fn read_log_last(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<LogSchema, Status>
     {
-        log::read_log_last(&self, device_id, model_id, tag)
+        self.call_retry(|| log::read_log_last(self, device_id, model_id, tag))
         .await
         .map(|v| v.into())
     }
@@ -1711,7 +1961,7 @@ impl Resource {
     pub async fn list_log_first(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_first(&self, number, device_id, model_id, tag)
+        self.call_long(|| log::list_log_first(self, number, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1719,7 +1969,7 @@ impl Resource {
     pub async fn list_log_first_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_first_offset(&self, number, offset, device_id, model_id, tag)
+        self.call_long(|| log::list_log_first_offset(self, number, offset, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1727,7 +1977,7 @@ impl Resource {
     pub async fn list_log_last(&self, number: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_last(&self, number, device_id, model_id, tag)
+        self.call_long(|| log::list_log_last(self, number, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1735,7 +1985,7 @@ impl Resource {
     pub async fn list_log_last_offset(&self, number: usize, offset: usize, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_last_offset(&self, number, offset, device_id, model_id, tag)
+        self.call_long(|| log::list_log_last_offset(self, number, offset, device_id, model_id, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1743,7 +1993,7 @@ impl Resource {
     pub async fn list_log_group_by_time(&self, timestamp: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_by_time(&self, timestamp, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_by_time(self, timestamp, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1751,7 +2001,7 @@ impl Resource {
     pub async fn list_log_group_by_latest(&self, latest: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_by_latest(&self, latest, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_by_latest(self, latest, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1759,7 +2009,7 @@ impl Resource {
     pub async fn list_log_group_by_range(&self, begin: DateTime<Utc>, end: DateTime<Utc>, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_by_range(&self, begin, end, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_by_range(self, begin, end, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1767,7 +2017,7 @@ impl Resource {
     pub async fn read_log_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<LogSchema, Status>
     {
-        log::read_log_group_first(&self, device_ids, model_ids, tag)
+        self.call_retry(|| log::read_log_group_first(self, device_ids, model_ids, tag))
         .await
         .map(|v| v.into())
     }
@@ -1775,7 +2025,7 @@ impl Resource {
     pub async fn read_log_group_last(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<LogSchema, Status>
     {
-        log::read_log_group_last(&self, device_ids, model_ids, tag)
+        self.call_retry(|| log::read_log_group_last(self, device_ids, model_ids, tag))
         .await
         .map(|v| v.into())
     }
@@ -1783,7 +2033,7 @@ impl Resource {
     pub async fn list_log_group_first(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_first(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_first(self, number, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1791,7 +2041,7 @@ impl Resource {
     pub async fn list_log_group_first_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_first_offset(&self, number, offset, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_first_offset(self, number, offset, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1799,7 +2049,7 @@ impl Resource {
     pub async fn list_log_group_last(&self, number: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_last(&self, number, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_last(self, number, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1807,7 +2057,7 @@ impl Resource {
     pub async fn list_log_group_last_offset(&self, number: usize, offset: usize, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<Vec<LogSchema>, Status>
     {
-        log::list_log_group_last_offset(&self, number, offset, device_ids, model_ids, tag)
+        self.call_long(|| log::list_log_group_last_offset(self, number, offset, device_ids, model_ids, tag))
         .await
         .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
@@ -1815,34 +2065,34 @@ impl Resource {
     pub async fn create_log(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: DataValue, tag: Option<i16>)
         -> Result<i32, Status>
     {
-        log::create_log(&self, timestamp, device_id, model_id, value, tag)
+        self.call(|| log::create_log(self, timestamp, device_id, model_id, value.clone(), tag))
         .await
     }
 
     pub async fn update_log(&self, id: i32, value: Option<DataValue>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        log::update_log(&self, id, value, tag)
+        self.call(|| log::update_log(self, id, value.clone(), tag))
         .await
     }
 
     pub async fn update_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, value: Option<DataValue>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        log::update_log_by_time(&self, timestamp, device_id, model_id, value, tag)
+        self.call(|| log::update_log_by_time(self, timestamp, device_id, model_id, value.clone(), tag))
         .await
     }
 
     pub async fn delete_log(&self, id: i32)
         -> Result<(), Status>
     {
-        log::delete_log(&self, id).await
+        self.call(|| log::delete_log(self, id)).await
     }
 
     pub async fn delete_log_by_time(&self, timestamp: DateTime<Utc>, device_id: Option<Uuid>, model_id: Option<Uuid>, tag: Option<i16>)
         -> Result<(), Status>
     {
-        log::delete_log_by_time(&self, timestamp, device_id, model_id, tag).await
+        self.call(|| log::delete_log_by_time(self, timestamp, device_id, model_id, tag)).await
     }
 
 }
@@ -1,13 +1,11 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
 use rmcs_resource_db::schema::value::{DataType, DataValue};
-use rmcs_resource_api::model::model_service_client::ModelServiceClient;
 use rmcs_resource_api::model::{
     ModelSchema, ModelId, ModelIds, ModelName, ModelCategory, ModelOption, TypeId, ModelUpdate, 
     ConfigSchema, ConfigId, ConfigUpdate, TagSchema, TagId, TagUpdate
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const MODEL_NOT_FOUND: &str = "requested model not found";
 const CONF_NOT_FOUND: &str = "requested config not found";
@@ -15,9 +13,7 @@ const CONF_NOT_FOUND: &str = "requested config not found";
 pub(crate) async fn read_model(resource: &Resource, id: Uuid)
     -> Result<ModelSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelId {
         id: id.as_bytes().to_vec()
     });
@@ -30,9 +26,7 @@ pub(crate) async fn read_model(resource: &Resource, id: Uuid)
 pub(crate) async fn list_model_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<ModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -45,9 +39,7 @@ pub(crate) async fn list_model_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_model_by_type(resource: &Resource, type_id: Uuid)
     -> Result<Vec<ModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(TypeId {
         id: type_id.as_bytes().to_vec()
     });
@@ -60,9 +52,7 @@ pub(crate) async fn list_model_by_type(resource: &Resource, type_id: Uuid)
 pub(crate) async fn list_model_by_name(resource: &Resource, name: &str)
     -> Result<Vec<ModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelName {
         name: name.to_owned()
     });
@@ -75,9 +65,7 @@ pub(crate) async fn list_model_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_model_by_category(resource: &Resource, category: &str)
     -> Result<Vec<ModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelCategory {
         category: category.to_owned()
     });
@@ -90,9 +78,7 @@ pub(crate) async fn list_model_by_category(resource: &Resource, category: &str)
 pub(crate) async fn list_model_option(resource: &Resource, type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>)
     -> Result<Vec<ModelSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelOption {
         type_id: type_id.map(|id| id.as_bytes().to_vec()),
         name: name.map(|s| s.to_owned()),
@@ -107,9 +93,7 @@ pub(crate) async fn list_model_option(resource: &Resource, type_id: Option<Uuid>
 pub(crate) async fn create_model(resource: &Resource, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelSchema {
         id: id.as_bytes().to_vec(),
         category: category.to_owned(),
@@ -128,9 +112,7 @@ pub(crate) async fn create_model(resource: &Resource, id: Uuid, data_type: &[Dat
 pub(crate) async fn update_model(resource: &Resource, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelUpdate {
         id: id.as_bytes().to_vec(),
         category: category.map(|s| s.to_owned()),
@@ -151,9 +133,7 @@ pub(crate) async fn update_model(resource: &Resource, id: Uuid, data_type: Optio
 pub(crate) async fn delete_model(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelId {
         id: id.as_bytes().to_vec()
     });
@@ -165,9 +145,7 @@ pub(crate) async fn delete_model(resource: &Resource, id: Uuid)
 pub(crate) async fn read_model_config(resource: &Resource, id: i32)
     -> Result<ConfigSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ConfigId {
         id
     });
@@ -180,9 +158,7 @@ pub(crate) async fn read_model_config(resource: &Resource, id: i32)
 pub(crate) async fn list_model_config_by_model(resource: &Resource, model_id: Uuid)
     -> Result<Vec<ConfigSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelId {
         id: model_id.as_bytes().to_vec()
     });
@@ -195,9 +171,7 @@ pub(crate) async fn list_model_config_by_model(resource: &Resource, model_id: Uu
 pub(crate) async fn create_model_config(resource: &Resource, model_id: Uuid, index: i32, name: &str, value: DataValue, category: &str)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ConfigSchema {
         id: 0,
         model_id: model_id.as_bytes().to_vec(),
@@ -216,9 +190,7 @@ pub(crate) async fn create_model_config(resource: &Resource, model_id: Uuid, ind
 pub(crate) async fn update_model_config(resource: &Resource, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ConfigUpdate {
         id,
         name: name.map(|s| s.to_owned()),
@@ -234,9 +206,7 @@ pub(crate) async fn update_model_config(resource: &Resource, id: i32, name: Opti
 pub(crate) async fn delete_model_config(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ConfigId {
         id
     });
@@ -248,9 +218,7 @@ pub(crate) async fn delete_model_config(resource: &Resource, id: i32)
 pub(crate) async fn read_tag(resource: &Resource, model_id: Uuid, tag: i16)
     -> Result<TagSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(TagId {
         model_id: model_id.as_bytes().to_vec(),
         tag: tag as i32
@@ -264,9 +232,7 @@ pub(crate) async fn read_tag(resource: &Resource, model_id: Uuid, tag: i16)
 pub(crate) async fn list_tag_by_model(resource: &Resource, model_id: Uuid)
     -> Result<Vec<TagSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(ModelId {
         id: model_id.as_bytes().to_vec()
     });
@@ -279,9 +245,7 @@ pub(crate) async fn list_tag_by_model(resource: &Resource, model_id: Uuid)
 pub(crate) async fn create_tag(resource: &Resource, model_id: Uuid, tag: i16, name: &str, members: &[i16])
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(TagSchema {
         model_id: model_id.as_bytes().to_vec(),
         tag: tag as i32,
@@ -297,9 +261,7 @@ pub(crate) async fn create_tag(resource: &Resource, model_id: Uuid, tag: i16, na
 pub(crate) async fn update_tag(resource: &Resource, model_id: Uuid, tag: i16, name: Option<&str>, members: Option<&[i16]>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(TagUpdate {
         model_id: model_id.as_bytes().to_vec(),
         tag: tag as i32,
@@ -315,9 +277,7 @@ pub(crate) async fn update_tag(resource: &Resource, model_id: Uuid, tag: i16, na
 pub(crate) async fn delete_tag(resource: &Resource, model_id: Uuid, tag: i16)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        ModelServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.model.clone();
     let request = Request::new(TagId {
         model_id: model_id.as_bytes().to_vec(),
         tag: tag as i32
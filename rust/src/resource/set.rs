@@ -1,22 +1,18 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_resource_api::set::set_service_client::SetServiceClient;
 use rmcs_resource_api::set::{
     SetSchema, SetId, SetIds, SetName, SetOption, SetUpdate, SetMemberRequest, SetMemberSwap,
     SetTemplateSchema, SetTemplateId, SetTemplateIds, SetTemplateName, SetTemplateOption, SetTemplateUpdate, 
     SetTemplateMemberRequest, SetTemplateMemberSwap
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const SET_NOT_FOUND: &str = "requested set not found";
 
 pub(crate) async fn read_set(resource: &Resource, id: Uuid)
     -> Result<SetSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetId {
         id: id.as_bytes().to_vec()
     });
@@ -29,9 +25,7 @@ pub(crate) async fn read_set(resource: &Resource, id: Uuid)
 pub(crate) async fn list_set_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<SetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -44,9 +38,7 @@ pub(crate) async fn list_set_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_set_by_template(resource: &Resource, template_id: Uuid)
     -> Result<Vec<SetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateId {
         id: template_id.as_bytes().to_vec()
     });
@@ -59,9 +51,7 @@ pub(crate) async fn list_set_by_template(resource: &Resource, template_id: Uuid)
 pub(crate) async fn list_set_by_name(resource: &Resource, name: &str)
     -> Result<Vec<SetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetName {
         name: name.to_owned()
     });
@@ -74,9 +64,7 @@ pub(crate) async fn list_set_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_set_option(resource: &Resource, template_id: Option<Uuid>, name: Option<&str>)
     -> Result<Vec<SetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetOption {
         template_id: template_id.map(|id| id.as_bytes().to_vec()),
         name: name.map(|s| s.to_owned())
@@ -90,9 +78,7 @@ pub(crate) async fn list_set_option(resource: &Resource, template_id: Option<Uui
 pub(crate) async fn create_set(resource: &Resource, id: Uuid, template_id: Uuid, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetSchema {
         id: id.as_bytes().to_vec(),
         template_id: template_id.as_bytes().to_vec(),
@@ -109,9 +95,7 @@ pub(crate) async fn create_set(resource: &Resource, id: Uuid, template_id: Uuid,
 pub(crate) async fn update_set(resource: &Resource, id: Uuid, template_id: Option<Uuid>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetUpdate {
         id: id.as_bytes().to_vec(),
         template_id: template_id.map(|id| id.as_bytes().to_vec()),
@@ -126,9 +110,7 @@ pub(crate) async fn update_set(resource: &Resource, id: Uuid, template_id: Optio
 pub(crate) async fn delete_set(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetId {
         id: id.as_bytes().to_vec()
     });
@@ -140,9 +122,7 @@ pub(crate) async fn delete_set(resource: &Resource, id: Uuid)
 pub(crate) async fn add_set_member(resource: &Resource, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetMemberRequest {
         id: id.as_bytes().to_vec(),
         device_id: device_id.as_bytes().to_vec(),
@@ -157,9 +137,7 @@ pub(crate) async fn add_set_member(resource: &Resource, id: Uuid, device_id: Uui
 pub(crate) async fn remove_set_member(resource: &Resource, id: Uuid, device_id: Uuid, model_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetMemberRequest {
         id: id.as_bytes().to_vec(),
         device_id: device_id.as_bytes().to_vec(),
@@ -174,9 +152,7 @@ pub(crate) async fn remove_set_member(resource: &Resource, id: Uuid, device_id:
 pub(crate) async fn swap_set_member(resource: &Resource, id: Uuid, device_id_1: Uuid, model_id_1: Uuid, device_id_2: Uuid, model_id_2: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetMemberSwap {
         id: id.as_bytes().to_vec(),
         device_id_1: device_id_1.as_bytes().to_vec(),
@@ -192,9 +168,7 @@ pub(crate) async fn swap_set_member(resource: &Resource, id: Uuid, device_id_1:
 pub(crate) async fn read_set_template(resource: &Resource, id: Uuid)
     -> Result<SetTemplateSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateId {
         id: id.as_bytes().to_vec()
     });
@@ -207,9 +181,7 @@ pub(crate) async fn read_set_template(resource: &Resource, id: Uuid)
 pub(crate) async fn list_set_template_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<SetTemplateSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -222,9 +194,7 @@ pub(crate) async fn list_set_template_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_set_template_by_name(resource: &Resource, name: &str)
     -> Result<Vec<SetTemplateSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateName {
         name: name.to_owned()
     });
@@ -237,9 +207,7 @@ pub(crate) async fn list_set_template_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_set_template_option(resource: &Resource, name: Option<&str>)
     -> Result<Vec<SetTemplateSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateOption {
         name: name.map(|s| s.to_owned())
     });
@@ -252,9 +220,7 @@ pub(crate) async fn list_set_template_option(resource: &Resource, name: Option<&
 pub(crate) async fn create_set_template(resource: &Resource, id: Uuid, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -270,9 +236,7 @@ pub(crate) async fn create_set_template(resource: &Resource, id: Uuid, name: &st
 pub(crate) async fn update_set_template(resource: &Resource, id: Uuid, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -286,9 +250,7 @@ pub(crate) async fn update_set_template(resource: &Resource, id: Uuid, name: Opt
 pub(crate) async fn delete_set_template(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateId {
         id: id.as_bytes().to_vec()
     });
@@ -300,9 +262,7 @@ pub(crate) async fn delete_set_template(resource: &Resource, id: Uuid)
 pub(crate) async fn add_set_template_member(resource: &Resource, id: Uuid, type_id: Uuid, model_id: Uuid, data_index: &[u8])
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateMemberRequest {
         id: id.as_bytes().to_vec(),
         type_id: type_id.as_bytes().to_vec(),
@@ -318,9 +278,7 @@ pub(crate) async fn add_set_template_member(resource: &Resource, id: Uuid, type_
 pub(crate) async fn remove_set_template_member(resource: &Resource, id: Uuid, index: usize)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateMemberRequest {
         id: id.as_bytes().to_vec(),
         type_id: Uuid::nil().as_bytes().to_vec(),
@@ -336,9 +294,7 @@ pub(crate) async fn remove_set_template_member(resource: &Resource, id: Uuid, in
 pub(crate) async fn swap_set_template_member(resource: &Resource, id: Uuid, index_1: usize, index_2: usize)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SetServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.set.clone();
     let request = Request::new(SetTemplateMemberSwap {
         id: id.as_bytes().to_vec(),
         template_index_1: index_1 as i32,
@@ -1,23 +1,19 @@
 use tonic::{Request, Status};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use rmcs_resource_api::slice::slice_service_client::SliceServiceClient;
 use rmcs_resource_api::slice::{
     SliceSchema, SliceId, SliceIds, SliceTime, SliceRange, SliceNameTime, SliceNameRange, SliceUpdate, SliceOption,
     SliceGroupTime, SliceGroupRange, SliceGroupOption,
     SliceSetSchema, SliceSetTime, SliceSetRange, SliceSetOption
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const SLICE_NOT_FOUND: &str = "requested slice not found";
 
 pub(crate) async fn read_slice(resource: &Resource, id: i32)
     -> Result<SliceSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceId {
         id
     });
@@ -30,9 +26,7 @@ pub(crate) async fn read_slice(resource: &Resource, id: i32)
 pub(crate) async fn list_slice_by_ids(resource: &Resource, ids: &[i32])
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceIds {
         ids: ids.to_vec()
     });
@@ -45,9 +39,7 @@ pub(crate) async fn list_slice_by_ids(resource: &Resource, ids: &[i32])
 pub(crate) async fn list_slice_by_time(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceTime {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -62,9 +54,7 @@ pub(crate) async fn list_slice_by_time(resource: &Resource, device_id: Uuid, mod
 pub(crate) async fn list_slice_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceRange {
         device_id: device_id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec(),
@@ -80,9 +70,7 @@ pub(crate) async fn list_slice_by_range(resource: &Resource, device_id: Uuid, mo
 pub(crate) async fn list_slice_by_name_time(resource: &Resource, name: &str, timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceNameTime {
         name: name.to_owned(),
         timestamp: timestamp.timestamp_micros()
@@ -96,9 +84,7 @@ pub(crate) async fn list_slice_by_name_time(resource: &Resource, name: &str, tim
 pub(crate) async fn list_slice_by_name_range(resource: &Resource, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceNameRange {
         name: name.to_owned(),
         begin: begin.timestamp_micros(),
@@ -113,9 +99,7 @@ pub(crate) async fn list_slice_by_name_range(resource: &Resource, name: &str, be
 pub(crate) async fn list_slice_option(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceOption {
         device_id: device_id.map(|id| id.as_bytes().to_vec()),
         model_id: model_id.map(|id| id.as_bytes().to_vec()),
@@ -132,9 +116,7 @@ pub(crate) async fn list_slice_option(resource: &Resource, device_id: Option<Uui
 pub(crate) async fn list_slice_group_by_time(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceGroupTime {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -149,9 +131,7 @@ pub(crate) async fn list_slice_group_by_time(resource: &Resource, device_ids: &[
 pub(crate) async fn list_slice_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceGroupRange {
         device_ids: device_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -167,9 +147,7 @@ pub(crate) async fn list_slice_group_by_range(resource: &Resource, device_ids: &
 pub(crate) async fn list_slice_group_option(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
     -> Result<Vec<SliceSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceGroupOption {
         device_ids: device_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
         model_ids: model_ids.unwrap_or_default().into_iter().map(|id| id.as_bytes().to_vec()).collect(),
@@ -186,9 +164,7 @@ pub(crate) async fn list_slice_group_option(resource: &Resource, device_ids: Opt
 pub(crate) async fn create_slice(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceSchema {
         id: 0,
         device_id: device_id.as_bytes().to_vec(),
@@ -207,9 +183,7 @@ pub(crate) async fn create_slice(resource: &Resource, device_id: Uuid, model_id:
 pub(crate) async fn update_slice(resource: &Resource, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceUpdate {
         id,
         timestamp_begin: timestamp_begin.map(|t| t.timestamp_micros()),
@@ -225,9 +199,7 @@ pub(crate) async fn update_slice(resource: &Resource, id: i32, timestamp_begin:
 pub(crate) async fn delete_slice(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceId {
         id
     });
@@ -239,9 +211,7 @@ pub(crate) async fn delete_slice(resource: &Resource, id: i32)
 pub(crate) async fn read_slice_set(resource: &Resource, id: i32)
     -> Result<SliceSetSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceId {
         id
     });
@@ -254,9 +224,7 @@ pub(crate) async fn read_slice_set(resource: &Resource, id: i32)
 pub(crate) async fn list_slice_set_by_ids(resource: &Resource, ids: &[i32])
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceIds {
         ids: ids.to_vec()
     });
@@ -269,9 +237,7 @@ pub(crate) async fn list_slice_set_by_ids(resource: &Resource, ids: &[i32])
 pub(crate) async fn list_slice_set_by_time(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceSetTime {
         set_id: set_id.as_bytes().to_vec(),
         timestamp: timestamp.timestamp_micros()
@@ -285,9 +251,7 @@ pub(crate) async fn list_slice_set_by_time(resource: &Resource, set_id: Uuid, ti
 pub(crate) async fn list_slice_set_by_range(resource: &Resource, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceSetRange {
         set_id: set_id.as_bytes().to_vec(),
         begin: begin.timestamp_micros(),
@@ -302,9 +266,7 @@ pub(crate) async fn list_slice_set_by_range(resource: &Resource, set_id: Uuid, b
 pub(crate) async fn list_slice_set_by_name_time(resource: &Resource, name: &str, timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceNameTime {
         name: name.to_owned(),
         timestamp: timestamp.timestamp_micros()
@@ -318,9 +280,7 @@ pub(crate) async fn list_slice_set_by_name_time(resource: &Resource, name: &str,
 pub(crate) async fn list_slice_set_by_name_range(resource: &Resource, name: &str, begin: DateTime<Utc>, end: DateTime<Utc>)
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceNameRange {
         name: name.to_owned(),
         begin: begin.timestamp_micros(),
@@ -335,9 +295,7 @@ pub(crate) async fn list_slice_set_by_name_range(resource: &Resource, name: &str
 pub(crate) async fn list_slice_set_option(resource: &Resource, set_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>)
     -> Result<Vec<SliceSetSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceSetOption {
         set_id: set_id.map(|id| id.as_bytes().to_vec()),
         name: name.map(|s| s.to_owned()),
@@ -353,9 +311,7 @@ pub(crate) async fn list_slice_set_option(resource: &Resource, set_id: Option<Uu
 pub(crate) async fn create_slice_set(resource: &Resource, set_id: Uuid, timestamp_begin: DateTime<Utc>, timestamp_end: DateTime<Utc>, name: &str, description: Option<&str>)
     -> Result<i32, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceSetSchema {
         id: 0,
         set_id: set_id.as_bytes().to_vec(),
@@ -373,9 +329,7 @@ pub(crate) async fn create_slice_set(resource: &Resource, set_id: Uuid, timestam
 pub(crate) async fn update_slice_set(resource: &Resource, id: i32, timestamp_begin: Option<DateTime<Utc>>, timestamp_end: Option<DateTime<Utc>>, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceUpdate {
         id,
         timestamp_begin: timestamp_begin.map(|t| t.timestamp_micros()),
@@ -391,9 +345,7 @@ pub(crate) async fn update_slice_set(resource: &Resource, id: i32, timestamp_beg
 pub(crate) async fn delete_slice_set(resource: &Resource, id: i32)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.slice.clone();
     let request = Request::new(SliceId {
         id
     });
@@ -1,20 +1,16 @@
 use tonic::{Request, Status};
 use uuid::Uuid;
-use rmcs_resource_api::device::device_service_client::DeviceServiceClient;
 use rmcs_resource_api::device::{
     TypeSchema, TypeId, TypeIds, TypeName, TypeOption, TypeUpdate, TypeModel
 };
 use crate::resource::Resource;
-use rmcs_api_server::utility::interceptor::TokenInterceptor;
 
 const TYPE_NOT_FOUND: &str = "requested type not found";
 
 pub(crate) async fn read_type(resource: &Resource, id: Uuid)
     -> Result<TypeSchema, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeId {
         id: id.as_bytes().to_vec()
     });
@@ -27,9 +23,7 @@ pub(crate) async fn read_type(resource: &Resource, id: Uuid)
 pub(crate) async fn list_type_by_ids(resource: &Resource, ids: &[Uuid])
     -> Result<Vec<TypeSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeIds {
         ids: ids.into_iter().map(|&id| id.as_bytes().to_vec()).collect()
     });
@@ -42,9 +36,7 @@ pub(crate) async fn list_type_by_ids(resource: &Resource, ids: &[Uuid])
 pub(crate) async fn list_type_by_name(resource: &Resource, name: &str)
     -> Result<Vec<TypeSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeName {
         name: name.to_owned()
     });
@@ -57,9 +49,7 @@ pub(crate) async fn list_type_by_name(resource: &Resource, name: &str)
 pub(crate) async fn list_type_option(resource: &Resource, name: Option<&str>)
     -> Result<Vec<TypeSchema>, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeOption {
         name: name.map(|s| s.to_owned())
     });
@@ -72,9 +62,7 @@ pub(crate) async fn list_type_option(resource: &Resource, name: Option<&str>)
 pub(crate) async fn create_type(resource: &Resource, id: Uuid, name: &str, description: Option<&str>)
     -> Result<Uuid, Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeSchema {
         id: id.as_bytes().to_vec(),
         name: name.to_owned(),
@@ -90,9 +78,7 @@ pub(crate) async fn create_type(resource: &Resource, id: Uuid, name: &str, descr
 pub(crate) async fn update_type(resource: &Resource, id: Uuid, name: Option<&str>, description: Option<&str>)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeUpdate {
         id: id.as_bytes().to_vec(),
         name: name.map(|s| s.to_owned()),
@@ -106,9 +92,7 @@ pub(crate) async fn update_type(resource: &Resource, id: Uuid, name: Option<&str
 pub(crate) async fn delete_type(resource: &Resource, id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeId {
         id: id.as_bytes().to_vec()
     });
@@ -120,9 +104,7 @@ pub(crate) async fn delete_type(resource: &Resource, id: Uuid)
 pub(crate) async fn add_type_model(resource: &Resource, id: Uuid, model_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeModel {
         id: id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec()
@@ -135,9 +117,7 @@ pub(crate) async fn add_type_model(resource: &Resource, id: Uuid, model_id: Uuid
 pub(crate) async fn remove_type_model(resource: &Resource, id: Uuid, model_id: Uuid)
     -> Result<(), Status>
 {
-    let interceptor = TokenInterceptor(resource.access_token.clone());
-    let mut client = 
-        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let mut client = resource.clients.device.clone();
     let request = Request::new(TypeModel {
         id: id.as_bytes().to_vec(),
         model_id: model_id.as_bytes().to_vec()
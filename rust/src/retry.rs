@@ -0,0 +1,116 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+use tonic::{Code, Status};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: bool,
+    pub codes: Vec<Code>
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            codes: vec![Code::Unavailable]
+        }
+    }
+}
+
+impl RetryPolicy {
+
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    // single attempt without any retry
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    // multiplier below one or not finite is replaced with one, so the backoff stay constant
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = if multiplier.is_finite() && multiplier >= 1.0 { multiplier } else { 1.0 };
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn codes(mut self, codes: &[Code]) -> Self {
+        self.codes = codes.to_vec();
+        self
+    }
+
+    pub fn is_retryable(&self, status: &Status) -> bool {
+        self.codes.contains(&status.code())
+    }
+
+    // backoff delay before the n-th retry without jitter, starting from 1,
+    // delay which overflow or can not be computed is bounded to maximum backoff
+    pub fn backoff_delay(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.multiplier.max(0.0).powi(exponent);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    fn sleep_delay(&self, retry: u32) -> Duration {
+        let delay = self.backoff_delay(retry);
+        if !self.jitter {
+            return delay;
+        }
+        // equal jitter, keep half of the delay and randomize the other half
+        let random = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+        delay / 2 + (delay / 2).mul_f64(random)
+    }
+
+    pub(crate) async fn run<T, F, Fut, G>(&self, f: F, guard: G)
+        -> Result<T, Status>
+        where F: Fn() -> Fut, Fut: Future<Output = Result<T, Status>>, G: Fn(&Status) -> Option<T>
+    {
+        let mut attempt = 1;
+        loop {
+            let status = match f().await {
+                Ok(value) => return Ok(value),
+                Err(status) => status
+            };
+            // previous attempt may be applied on the server although its response was lost
+            if attempt > 1 && let Some(value) = guard(&status) {
+                return Ok(value);
+            }
+            if attempt >= self.max_attempts || !self.is_retryable(&status) {
+                return Err(status);
+            }
+            tokio::time::sleep(self.sleep_delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+}
+
+// idempotency guard for create mutation with client generated id
+pub fn already_exists<T: Clone>(value: T) -> impl Fn(&Status) -> Option<T> {
+    move |status| (status.code() == Code::AlreadyExists).then(|| value.clone())
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tonic::transport::Endpoint;
+use uuid::Uuid;
+use crate::auth::{Auth, ApiSchema};
+use crate::resource::Resource;
+use crate::claims::AccessClaims;
+use crate::error::Error;
+
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+const REFRESH_RETRY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct SessionApi {
+    pub id: Uuid,
+    pub name: String,
+    pub address: String,
+    pub resource: Resource
+}
+
+#[derive(Debug)]
+pub struct Session {
+    auth: Auth,
+    user_id: Uuid,
+    auth_token: String,
+    apis: Vec<SessionApi>,
+    refresh_tasks: Vec<JoinHandle<()>>
+}
+
+impl Session {
+
+    pub async fn login(auth: &Auth, username: &str, password: &str)
+        -> Result<Session, Error>
+    {
+        Self::login_with(auth, username, password, |api| {
+            let endpoint = Endpoint::from_shared(api_address(&api.address))
+                .map_err(|_| Error::InvalidAddress(api.address.clone()))?;
+            Ok(Resource::new_with_channel(endpoint.connect_lazy()))
+        }).await
+    }
+
+    // connect function create resource client of an api before its tokens are attached
+    pub(crate) async fn login_with<F>(auth: &Auth, username: &str, password: &str, connect: F)
+        -> Result<Session, Error>
+        where F: Fn(&ApiSchema) -> Result<Resource, Error>
+    {
+        let login = auth.user_login(username, password).await?;
+        let user_id = parse_id(&login.user_id)?;
+        let auth = auth.clone().with_token(&login.auth_token);
+        // read name and address of all apis which the user has access to
+        let ids = login.access_tokens.iter()
+            .map(|t| parse_id(&t.api_id))
+            .collect::<Result<Vec<Uuid>, Error>>()?;
+        let api_schemas = auth.list_api_by_ids(&ids).await?;
+        let mut apis = Vec::new();
+        for (token, api_id) in login.access_tokens.into_iter().zip(ids) {
+            let api = match api_schemas.iter().find(|a| a.id == api_id) {
+                Some(value) => value,
+                None => continue
+            };
+            let resource = connect(api)?
+                .with_token(&token.access_token, &token.refresh_token)
+                .with_auth(&auth, api_id);
+            apis.push(SessionApi {
+                id: api_id,
+                name: api.name.clone(),
+                address: api.address.clone(),
+                resource
+            });
+        }
+        let mut session = Session {
+            auth,
+            user_id,
+            auth_token: login.auth_token,
+            apis,
+            refresh_tasks: Vec::new()
+        };
+        session.start_refresh(REFRESH_MARGIN);
+        Ok(session)
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn auth_token(&self) -> &str {
+        &self.auth_token
+    }
+
+    pub fn auth(&self) -> Auth {
+        self.auth.clone()
+    }
+
+    pub fn apis(&self) -> &[SessionApi] {
+        &self.apis
+    }
+
+    pub fn resource(&self, api_id: Uuid) -> Option<Resource> {
+        self.apis.iter()
+            .find(|a| a.id == api_id)
+            .map(|a| a.resource.clone())
+    }
+
+    pub fn resource_by_name(&self, name: &str) -> Option<Resource> {
+        self.apis.iter()
+            .find(|a| a.name == name)
+            .map(|a| a.resource.clone())
+    }
+
+    // every access token is refreshed the margin before it expires
+    pub fn start_refresh(&mut self, margin: Duration) {
+        self.stop_refresh();
+        for api in &self.apis {
+            let resource = api.resource.clone();
+            let task = tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(refresh_delay(&resource.token().0, margin)).await;
+                    // failed refresh is retried shortly or on the next unauthenticated call
+                    if resource.refresh().await.is_err() {
+                        tokio::time::sleep(REFRESH_RETRY).await;
+                    }
+                }
+            });
+            self.refresh_tasks.push(task);
+        }
+    }
+
+    pub fn stop_refresh(&mut self) {
+        for task in self.refresh_tasks.drain(..) {
+            task.abort();
+        }
+    }
+
+    pub async fn logout(mut self)
+        -> Result<(), Error>
+    {
+        self.stop_refresh();
+        self.auth.user_logout(self.user_id, &self.auth_token).await?;
+        Ok(())
+    }
+
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.stop_refresh();
+    }
+}
+
+// token with lifetime shorter than the margin is refreshed halfway to its expiry instead of immediately,
+// expired token is refreshed immediately and token which can not be decoded after the retry delay
+fn refresh_delay(access_token: &str, margin: Duration) -> Duration {
+    match AccessClaims::decode(access_token) {
+        Ok(claims) => {
+            let expire = claims.time_to_expire().to_std().unwrap_or_default();
+            expire.saturating_sub(margin).max(expire / 2)
+        },
+        Err(_) => REFRESH_RETRY
+    }
+}
+
+pub(crate) fn parse_id(bytes: &[u8]) -> Result<Uuid, Error> {
+    Uuid::from_slice(bytes).map_err(|e| Error::Parse(format!("invalid id: {}", e)))
+}
+
+pub(crate) fn api_address(address: &str) -> String {
+    if address.contains("://") {
+        address.to_owned()
+    } else {
+        format!("http://{}", address.trim_start_matches("//"))
+    }
+}
+
+// tonic only use TLS for https address, so address of TLS client is upgraded to https
+pub(crate) fn tls_address(address: &str, tls: bool) -> String {
+    let address = api_address(address);
+    match address.strip_prefix("http://") {
+        Some(host) if tls => format!("https://{}", host),
+        _ => address
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use crate::config::ClientConfig;
+use crate::error::Error;
+use crate::retry::RetryPolicy;
+use crate::session::tls_address;
+
+pub const SERVER_ADDRESS: &str = "SERVER_ADDRESS";
+pub const SERVER_ADDRESS_AUTH: &str = "SERVER_ADDRESS_AUTH";
+pub const SERVER_ADDRESS_RESOURCE: &str = "SERVER_ADDRESS_RESOURCE";
+pub const TLS_CA_CERT: &str = "TLS_CA_CERT";
+pub const TLS_CLIENT_CERT: &str = "TLS_CLIENT_CERT";
+pub const TLS_CLIENT_KEY: &str = "TLS_CLIENT_KEY";
+pub const TLS_DOMAIN: &str = "TLS_DOMAIN";
+pub const CONNECT_TIMEOUT_MS: &str = "CONNECT_TIMEOUT_MS";
+pub const REQUEST_TIMEOUT_MS: &str = "REQUEST_TIMEOUT_MS";
+pub const LONG_TIMEOUT_MS: &str = "LONG_TIMEOUT_MS";
+pub const AUTH_USERNAME: &str = "AUTH_USERNAME";
+pub const AUTH_PASSWORD: &str = "AUTH_PASSWORD";
+pub const RETRY_MAX_ATTEMPTS: &str = "RETRY_MAX_ATTEMPTS";
+pub const RETRY_BACKOFF_MS: &str = "RETRY_BACKOFF_MS";
+pub const PLAINTEXT_PASSWORD: &str = "PLAINTEXT_PASSWORD";
+
+pub const SETTING_KEYS: [&str; 15] = [
+    SERVER_ADDRESS, SERVER_ADDRESS_AUTH, SERVER_ADDRESS_RESOURCE,
+    TLS_CA_CERT, TLS_CLIENT_CERT, TLS_CLIENT_KEY, TLS_DOMAIN,
+    CONNECT_TIMEOUT_MS, REQUEST_TIMEOUT_MS, LONG_TIMEOUT_MS,
+    AUTH_USERNAME, AUTH_PASSWORD,
+    RETRY_MAX_ATTEMPTS, RETRY_BACKOFF_MS,
+    PLAINTEXT_PASSWORD
+];
+
+const DOTENV_FILE: &str = ".env";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    values: HashMap<String, String>
+}
+
+impl Settings {
+
+    // precedence from highest: process environment, .env file, TOML file
+    pub fn load(env_file: Option<&Path>, toml_file: Option<&Path>) -> Result<Self, Error> {
+        let mut settings = Settings::default();
+        if let Some(path) = toml_file {
+            let content = std::fs::read_to_string(path)?;
+            settings.merge(Settings::from_toml(&content)?);
+        }
+        if let Some(path) = env_file {
+            settings.merge(Settings::from_dotenv(path)?);
+        }
+        settings.merge(Settings::from_process_env());
+        Ok(settings)
+    }
+
+    // read process environment and .env file in working directory if exists
+    pub fn from_env() -> Result<Self, Error> {
+        let env_file = Path::new(DOTENV_FILE);
+        Settings::load(env_file.exists().then_some(env_file), None)
+    }
+
+    pub fn from_process_env() -> Self {
+        let values = SETTING_KEYS.iter()
+            .filter_map(|key| std::env::var(key).ok().map(|value| (key.to_string(), value)))
+            .collect();
+        Settings { values }
+    }
+
+    pub fn from_dotenv(path: &Path) -> Result<Self, Error> {
+        let error = |e: dotenvy::Error| Error::Config(format!("{}: {}", path.display(), e));
+        let mut values = HashMap::new();
+        for item in dotenvy::from_path_iter(path).map_err(error)? {
+            let (key, value) = item.map_err(error)?;
+            values.insert(key, value);
+        }
+        Ok(Settings { values })
+    }
+
+    // nested table keys are joined with underscore, so [server] address_auth is SERVER_ADDRESS_AUTH
+    pub fn from_toml(content: &str) -> Result<Self, Error> {
+        let table: toml::Table = toml::from_str(content)
+            .map_err(|e| Error::Parse(e.to_string()))?;
+        let mut values = HashMap::new();
+        flatten_toml("", &table, &mut values);
+        Ok(Settings { values })
+    }
+
+    // empty value is treated as unset, so it does not override value from lower precedence source
+    pub fn merge(&mut self, other: Settings) {
+        self.values.extend(other.values.into_iter().filter(|(_, value)| !value.is_empty()));
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_owned(), value.to_owned());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str()).filter(|value| !value.is_empty())
+    }
+
+    pub fn require(&self, key: &str) -> Result<&str, Error> {
+        self.get(key).ok_or(Error::Config(format!("missing configuration key {}", key)))
+    }
+
+    fn parse<T: std::str::FromStr>(&self, key: &str) -> Result<Option<T>, Error> {
+        match self.get(key) {
+            Some(value) => value.parse()
+                .map(Some)
+                .map_err(|_| Error::Config(format!("invalid value of {}: {}", key, value))),
+            None => Ok(None)
+        }
+    }
+
+    fn duration(&self, key: &str) -> Result<Option<Duration>, Error> {
+        Ok(self.parse::<u64>(key)?.map(Duration::from_millis))
+    }
+
+    pub fn client_config(&self, address_key: &str) -> Result<ClientConfig, Error> {
+        let address = self.require(address_key)?;
+        let tls = [TLS_CA_CERT, TLS_CLIENT_CERT, TLS_DOMAIN].iter().any(|key| self.get(key).is_some());
+        // address in env file may be written without scheme like //127.0.0.1:9001
+        let mut config = ClientConfig::new(&tls_address(address, tls));
+        if let Some(path) = self.get(TLS_CA_CERT) {
+            config = config.ca_certificate_file(path)?;
+        }
+        match (self.get(TLS_CLIENT_CERT), self.get(TLS_CLIENT_KEY)) {
+            (Some(cert), Some(key)) => config = config.identity_file(cert, key)?,
+            (Some(_), None) => return Err(Error::Config(format!("missing configuration key {}", TLS_CLIENT_KEY))),
+            (None, Some(_)) => return Err(Error::Config(format!("missing configuration key {}", TLS_CLIENT_CERT))),
+            (None, None) => {}
+        }
+        if let Some(domain) = self.get(TLS_DOMAIN) {
+            config = config.domain(domain);
+        }
+        config.connect_timeout = self.duration(CONNECT_TIMEOUT_MS)?;
+        config.timeout = self.duration(REQUEST_TIMEOUT_MS)?;
+        config.long_timeout = self.duration(LONG_TIMEOUT_MS)?;
+        config.plaintext_password = self.parse(PLAINTEXT_PASSWORD)?.unwrap_or_default();
+        config.retry = self.retry_policy()?;
+        Ok(config)
+    }
+
+    // server specific address fall back to the shared SERVER_ADDRESS
+    fn server_config(&self, address_key: &str) -> Result<ClientConfig, Error> {
+        match (self.get(address_key), self.get(SERVER_ADDRESS)) {
+            (None, Some(_)) => self.client_config(SERVER_ADDRESS),
+            _ => self.client_config(address_key)
+        }
+    }
+
+    pub fn auth_config(&self) -> Result<ClientConfig, Error> {
+        self.server_config(SERVER_ADDRESS_AUTH)
+    }
+
+    pub fn resource_config(&self) -> Result<ClientConfig, Error> {
+        self.server_config(SERVER_ADDRESS_RESOURCE)
+    }
+
+    pub fn credentials(&self) -> Result<(String, String), Error> {
+        Ok((self.require(AUTH_USERNAME)?.to_owned(), self.require(AUTH_PASSWORD)?.to_owned()))
+    }
+
+    pub fn retry_max_attempts(&self) -> Result<Option<u32>, Error> {
+        self.parse(RETRY_MAX_ATTEMPTS)
+    }
+
+    pub fn retry_backoff(&self) -> Result<Option<Duration>, Error> {
+        self.duration(RETRY_BACKOFF_MS)
+    }
+
+    pub fn retry_policy(&self) -> Result<RetryPolicy, Error> {
+        let mut policy = RetryPolicy::default();
+        if let Some(max_attempts) = self.retry_max_attempts()? {
+            policy.max_attempts = max_attempts;
+        }
+        if let Some(backoff) = self.retry_backoff()? {
+            policy.initial_backoff = backoff;
+        }
+        Ok(policy)
+    }
+
+}
+
+fn flatten_toml(prefix: &str, table: &toml::Table, values: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() { key.to_uppercase() } else { format!("{}_{}", prefix, key.to_uppercase()) };
+        match value {
+            toml::Value::Table(table) => flatten_toml(&key, table, values),
+            toml::Value::String(value) => { values.insert(key, value.clone()); },
+            value => { values.insert(key, value.to_string()); }
+        }
+    }
+}
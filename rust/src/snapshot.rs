@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::path::Path;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+use crate::auth::{Auth, DataType, DataValue, ProfileMode};
+use crate::auth::utility::{generate_access_key, generate_token_string};
+use crate::error::Error;
+
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub created: DateTime<Utc>,
+    pub apis: Vec<ApiSnapshot>,
+    pub users: Vec<UserSnapshot>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiSnapshot {
+    pub id: Uuid,
+    pub name: String,
+    pub address: String,
+    pub category: String,
+    pub description: String,
+    #[serde(default)]
+    pub access_key: Option<Vec<u8>>,
+    pub procedures: Vec<ProcedureSnapshot>,
+    pub roles: Vec<RoleSnapshot>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcedureSnapshot {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleSnapshot {
+    pub id: Uuid,
+    pub name: String,
+    pub multi: bool,
+    pub ip_lock: bool,
+    pub access_duration: i32,
+    pub refresh_duration: i32,
+    pub procedures: Vec<Uuid>,
+    pub profiles: Vec<RoleProfileSnapshot>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleProfileSnapshot {
+    pub name: String,
+    pub value_type: i32,
+    pub mode: i32
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserSnapshot {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub phone: String,
+    pub roles: Vec<Uuid>,
+    pub profiles: Vec<UserProfileSnapshot>
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileSnapshot {
+    pub name: String,
+    pub value_type: i32,
+    pub value: Vec<u8>,
+    pub order: i16
+}
+
+// password hashes and tokens are not exported since they can not be recreated through the auth api
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    pub secrets: bool
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportOptions {
+    pub skip_secrets: bool,
+    pub passwords: HashMap<Uuid, String>,
+    pub access_keys: HashMap<Uuid, Vec<u8>>,
+    pub default_password: Option<String>
+}
+
+// passwords and access keys generated on import since they were not provided nor exported
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    pub passwords: HashMap<Uuid, String>,
+    pub access_keys: HashMap<Uuid, Vec<u8>>
+}
+
+impl ImportOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn skip_secrets(mut self) -> Self {
+        self.skip_secrets = true;
+        self
+    }
+
+    pub fn password(mut self, id: Uuid, password: &str) -> Self {
+        self.passwords.insert(id, password.to_owned());
+        self
+    }
+
+    pub fn access_key(mut self, api_id: Uuid, access_key: &[u8]) -> Self {
+        self.access_keys.insert(api_id, access_key.to_vec());
+        self
+    }
+
+    pub fn default_password(mut self, password: &str) -> Self {
+        self.default_password = Some(password.to_owned());
+        self
+    }
+
+    // passwords are not exported, so plain password must be provided or generated when secrets are skipped
+    fn password_of(&self, id: Uuid, name: &str, report: &mut ImportReport) -> Result<String, Error> {
+        if let Some(value) = self.passwords.get(&id).or(self.default_password.as_ref()) {
+            return Ok(value.clone());
+        }
+        if !self.skip_secrets {
+            return Err(Error::InvalidArgument(format!("password for {} is not provided", name)));
+        }
+        let password = generate_token_string();
+        report.passwords.insert(id, password.clone());
+        Ok(password)
+    }
+
+    fn access_key_of(&self, api_id: Uuid, access_key: &Option<Vec<u8>>, report: &mut ImportReport) -> Vec<u8> {
+        if let Some(value) = self.access_keys.get(&api_id) {
+            return value.clone();
+        }
+        match (access_key, self.skip_secrets) {
+            (Some(value), false) => value.clone(),
+            _ => {
+                let access_key = generate_access_key();
+                report.access_keys.insert(api_id, access_key.clone());
+                access_key
+            }
+        }
+    }
+
+}
+
+impl Snapshot {
+
+    pub async fn export(auth: &Auth, options: &ExportOptions) -> Result<Snapshot, Error> {
+        let mut apis = Vec::new();
+        // map api id and role name to role id for linking user roles
+        let mut role_ids: HashMap<(Uuid, String), Uuid> = HashMap::new();
+        for api in auth.list_api_option(None, None).await? {
+            let procedures = auth.list_procedure_by_api(api.id).await?
+                .into_iter()
+                .map(|p| ProcedureSnapshot { id: p.id, name: p.name, description: p.description })
+                .collect();
+            let mut roles = Vec::new();
+            for role in auth.list_role_by_api(api.id).await? {
+                let profiles = auth.list_role_profile_by_role(role.id).await?
+                    .into_iter()
+                    .map(|p| RoleProfileSnapshot { name: p.name, value_type: p.value_type.into(), mode: p.mode.into() })
+                    .collect();
+                role_ids.insert((api.id, role.name.clone()), role.id);
+                roles.push(RoleSnapshot {
+                    id: role.id,
+                    name: role.name,
+                    multi: role.multi,
+                    ip_lock: role.ip_lock,
+                    access_duration: role.access_duration,
+                    refresh_duration: role.refresh_duration,
+                    procedures: role.procedures,
+                    profiles
+                });
+            }
+            apis.push(ApiSnapshot {
+                id: api.id,
+                name: api.name,
+                address: api.address,
+                category: api.category,
+                description: api.description,
+                access_key: options.secrets.then_some(api.access_key),
+                procedures,
+                roles
+            });
+        }
+        let mut users = Vec::new();
+        for user in auth.list_user_option(None, None, None).await? {
+            let roles = user.roles.iter()
+                .filter_map(|r| role_ids.get(&(r.api_id, r.role.clone())).copied())
+                .collect();
+            let profiles = auth.list_user_profile_by_user(user.id).await?
+                .into_iter()
+                .map(|p| UserProfileSnapshot { name: p.name, value_type: p.value.get_type().into(), value: p.value.to_bytes(), order: p.order })
+                .collect();
+            users.push(UserSnapshot {
+                id: user.id,
+                name: user.name,
+                email: user.email,
+                phone: user.phone,
+                roles,
+                profiles
+            });
+        }
+        Ok(Snapshot {
+            version: SNAPSHOT_VERSION,
+            created: Utc::now(),
+            apis,
+            users
+        })
+    }
+
+    pub async fn import(&self, auth: &Auth, options: &ImportOptions) -> Result<ImportReport, Error> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(Error::Parse(format!("unsupported snapshot version {}", self.version)));
+        }
+        let mut report = ImportReport::default();
+        for api in &self.apis {
+            let password = options.password_of(api.id, &api.name, &mut report)?;
+            let access_key = options.access_key_of(api.id, &api.access_key, &mut report);
+            auth.create_api(api.id, &api.name, &api.address, &api.category, &api.description, &password, &access_key).await?;
+            for procedure in &api.procedures {
+                auth.create_procedure(procedure.id, api.id, &procedure.name, &procedure.description).await?;
+            }
+            for role in &api.roles {
+                auth.create_role(role.id, api.id, &role.name, role.multi, role.ip_lock, role.access_duration, role.refresh_duration).await?;
+                for procedure_id in &role.procedures {
+                    auth.add_role_access(role.id, *procedure_id).await?;
+                }
+                for profile in &role.profiles {
+                    auth.create_role_profile(role.id, &profile.name, DataType::from(profile.value_type), ProfileMode::from(profile.mode)).await?;
+                }
+            }
+        }
+        for user in &self.users {
+            let password = options.password_of(user.id, &user.name, &mut report)?;
+            auth.create_user(user.id, &user.name, &user.email, &user.phone, &password).await?;
+            for role_id in &user.roles {
+                auth.add_user_role(user.id, *role_id).await?;
+            }
+            let mut profiles: Vec<&UserProfileSnapshot> = user.profiles.iter().collect();
+            profiles.sort_by_key(|p| p.order);
+            for profile in profiles {
+                let value = DataValue::from_bytes(&profile.value, DataType::from(profile.value_type));
+                auth.create_user_profile(user.id, &profile.name, value).await?;
+            }
+        }
+        Ok(report)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    pub fn from_json(content: &str) -> Result<Snapshot, Error> {
+        serde_json::from_str(content).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    pub fn write_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Snapshot, Error> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+}
@@ -1,21 +1,113 @@
 #[cfg(test)]
 mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
     use chrono::{DateTime, Utc};
     use uuid::Uuid;
     use argon2::{Argon2, PasswordHash, PasswordVerifier};
     use rmcs_api_client::resource::{ModelConfigSchema, DeviceConfigSchema};
     use rmcs_api_client::resource::{DataType::*, DataValue::{*, self}};
     use rmcs_api_client::resource::SetMember;
+    use rmcs_api_client::resource::{ModelApi, ModelSchema, TagSchema, DataType};
+    use rmcs_api_client::auth::{RoleProfileSchema, UserProfileSchema, ProfileError};
+    use rmcs_api_client::auth::validator::validate_profiles;
+    use rmcs_api_client::auth::UserProfile;
+    use rmcs_api_client::auth::{AuditOptions, TokenStatus, TokenIp, IpUpdate, AccessResolver, SyncOptions, TokenRevoke};
+    use rmcs_api_client::auth::ip::ip_lock_allowed;
+    use rmcs_api_client::auth::sync::descriptor_methods;
     use rmcs_api_client::resource::tag;
-    use rmcs_api_client::{Auth, Resource};
+    use rmcs_api_client::{Auth, Resource, Session, Error, AccessClaims, CallOptions, ClientConfig, RetryPolicy, ApiRegistry, RmcsClient};
     use rmcs_api_client::auth::utility::generate_access_key;
     use rmcs_api_client::auth::ProfileMode::*;
+    use rmcs_api_client::provision::{Manifest, PlanOptions};
+    use rmcs_api_client::snapshot::{Snapshot, ApiSnapshot, ExportOptions, ImportOptions, SNAPSHOT_VERSION};
     use rmcs_api_server::utility::test::{TestServerKind, TestServer};
+    use rmcs_resource_api::model::model_service_server;
+    use std::sync::Arc;
+    use std::convert::Infallible;
+    use std::task::{Context, Poll};
+    use tokio::sync::Mutex;
+    use tonic::Status;
+    use tonic::body::Body;
+    use tonic::codegen::{http, BoxFuture, Service};
+    use tonic::metadata::MetadataMap;
+    use tonic::server::{Grpc, NamedService};
+    use tonic::transport::{Server, server::TcpIncoming};
+    use tonic_prost::ProstCodec;
+
+    // test servers listen on fixed addresses and share the test databases,
+    // so tests using the same server kind must not run at the same time
+    static AUTH_SERVER: Mutex<()> = Mutex::const_new(());
+    static RESOURCE_SERVER: Mutex<()> = Mutex::const_new(());
+
+    const ADMIN_PASSWORD: &str = "Adm1n_P4s5w0rd";
+    const USER_PASSWORD: &str = "Us3r_P4s5w0rd";
+
+    type Requests = Arc<std::sync::Mutex<Vec<MetadataMap>>>;
+
+    // model service which only record request metadata, request carrying the rejected token is unauthenticated
+    // and other requests get an empty response
+    #[derive(Clone)]
+    struct TokenServer {
+        rejected: std::string::String,
+        requests: Requests
+    }
+
+    impl NamedService for TokenServer {
+        const NAME: &'static str = model_service_server::SERVICE_NAME;
+    }
+
+    impl Service<http::Request<Body>> for TokenServer {
+        type Response = http::Response<Body>;
+        type Error = Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+            let server = self.clone();
+            Box::pin(async move {
+                Ok(Grpc::new(ProstCodec::<(), ()>::default()).unary(server, request).await)
+            })
+        }
+    }
+
+    impl Service<tonic::Request<()>> for TokenServer {
+        type Response = tonic::Response<()>;
+        type Error = Status;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+            let metadata = request.metadata().clone();
+            let rejected = metadata.get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.ends_with(&self.rejected));
+            self.requests.lock().unwrap().push(metadata);
+            Box::pin(async move {
+                match rejected {
+                    true => Err(Status::unauthenticated("access token expired")),
+                    false => Ok(tonic::Response::new(()))
+                }
+            })
+        }
+    }
+
+    async fn start_token_server(rejected: &str) -> (std::string::String, Requests)
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let server = TokenServer { rejected: rejected.to_owned(), requests: Requests::default() };
+        let requests = server.requests.clone();
+        tokio::spawn(Server::builder().add_service(server).serve_with_incoming(TcpIncoming::from(listener)));
+        (address, requests)
+    }
 
     #[tokio::test]
     async fn test_auth()
     {
         unsafe { std::env::set_var("RUST_BACKTRACE", "1"); }
+        let _lock = AUTH_SERVER.lock().await;
 
         // start auth server
         let auth_server = TestServer::new(TestServerKind::Auth);
@@ -164,10 +256,10 @@ mod tests {
         let expire1 = DateTime::parse_from_str("2023-01-01 00:00:00 +0000", "%Y-%m-%d %H:%M:%S %z").unwrap().into();
         let expire2 = DateTime::parse_from_str("2023-01-01 12:00:00 +0000", "%Y-%m-%d %H:%M:%S %z").unwrap().into();
         let auth_token = "rGKrHrDuWXt2CDbjmrt1SHbmea86wIQb";
-        let (access_id1, _, auth_token1) = auth.create_access_token(user_id1, auth_token, expire1, &[192, 168, 0, 1]).await.unwrap();
+        let (access_id1, _, auth_token1) = auth.create_access_token(user_id1, auth_token, expire1, Some(IpAddr::from([192, 168, 0, 1]))).await.unwrap();
         let access_id2 = access_id1 + 1;
-        auth.create_auth_token(user_id1, expire2, &[192, 168, 0, 1], 1).await.unwrap();
-        auth.create_access_token(user_id1, auth_token, expire1, &[]).await.unwrap();
+        auth.create_auth_token(user_id1, expire2, Some(IpAddr::from([192, 168, 0, 1])), 1).await.unwrap();
+        auth.create_access_token(user_id1, auth_token, expire1, None).await.unwrap();
 
         // get token data
         let access_token = auth.read_access_token(access_id2).await.unwrap();
@@ -183,8 +275,8 @@ mod tests {
 
         // update token
         let expire3 = DateTime::parse_from_str("2023-01-01 18:00:00 +0000", "%Y-%m-%d %H:%M:%S %z").unwrap().into();
-        auth.update_access_token(access_id2, Some(expire3), None).await.unwrap();
-        auth.update_auth_token(&auth_token1, Some(expire3), Some(&[192, 168, 0, 100])).await.unwrap();
+        auth.update_access_token(access_id2, Some(expire3), IpUpdate::Keep).await.unwrap();
+        auth.update_auth_token(&auth_token1, Some(expire3), IpUpdate::Set(IpAddr::from([192, 168, 0, 100]))).await.unwrap();
 
         // get updated token
         let new_access_token = auth.read_access_token(access_id2).await.unwrap();
@@ -250,10 +342,279 @@ mod tests {
         auth_server.stop_server();
     }
 
+    #[tokio::test]
+    async fn test_auth_access()
+    {
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        // resource API with read and delete procedures, "administrator" can call both and "username" can only read
+        let api_id = auth.create_api(Uuid::new_v4(), "resource", "localhost:9002", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let read_id = auth.create_procedure(Uuid::new_v4(), api_id, "ReadData", "").await.unwrap();
+        let delete_id = auth.create_procedure(Uuid::new_v4(), api_id, "DeleteData", "").await.unwrap();
+        let admin_role_id = auth.create_role(Uuid::new_v4(), api_id, "admin", false, false, 900, 28800).await.unwrap();
+        auth.add_role_access(admin_role_id, read_id).await.unwrap();
+        auth.add_role_access(admin_role_id, delete_id).await.unwrap();
+        let user_role_id = auth.create_role(Uuid::new_v4(), api_id, "user", true, false, 900, 28800).await.unwrap();
+        auth.add_role_access(user_role_id, read_id).await.unwrap();
+        let admin_id = auth.create_user(Uuid::new_v4(), "administrator", "admin@mail.co", "", ADMIN_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, admin_role_id).await.unwrap();
+        let user_id = auth.create_user(Uuid::new_v4(), "username", "user@mail.co", "", USER_PASSWORD).await.unwrap();
+        auth.add_user_role(user_id, user_role_id).await.unwrap();
+
+        // check user access to procedures of resource API
+        let mut resolver = AccessResolver::new(&auth);
+        assert!(resolver.can(admin_id, "resource", "DeleteData").await.unwrap());
+        assert!(resolver.can(user_id, "resource", "ReadData").await.unwrap());
+        assert!(!resolver.can(user_id, "resource", "DeleteData").await.unwrap());
+        assert!(!resolver.can(admin_id, "resource", "Unregistered").await.unwrap());
+
+        // matrix row of every user of the API, column of every procedure
+        let matrix = resolver.matrix("resource").await.unwrap();
+        let admin_row = matrix.rows.iter().find(|r| r.user_id == admin_id).unwrap();
+        let user_row = matrix.rows.iter().find(|r| r.user_id == user_id).unwrap();
+        let read = matrix.procedures.iter().position(|p| p == "ReadData").unwrap();
+
+        assert_eq!(matrix.api_id, api_id);
+        assert_eq!(matrix.procedures.len(), 2);
+        assert_eq!(admin_row.roles, ["admin"]);
+        assert!(admin_row.allowed.iter().all(|a| *a));
+        assert_eq!(user_row.roles, ["user"]);
+        assert_eq!(user_row.allowed.iter().filter(|a| **a).count(), 1);
+        assert!(user_row.allowed[read]);
+
+        // resolved roles are cached until cleared
+        auth.remove_user_role(user_id, user_role_id).await.unwrap();
+        assert!(resolver.can(user_id, "resource", "ReadData").await.unwrap());
+        resolver.clear();
+        assert!(!resolver.can(user_id, "resource", "ReadData").await.unwrap());
+
+        // the service has ReadData and CreateData methods, dry run report the changes without changing procedures
+        let descriptor = service_descriptor("data", &[("DataService", &["ReadData", "CreateData"])]);
+        let options = SyncOptions { dry_run: true, ..Default::default() };
+        let report = auth.sync_procedure(api_id, &descriptor, &options).await.unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.created, ["CreateData"]);
+        assert_eq!(report.deleted, ["DeleteData"]);
+        assert_eq!(report.unchanged, ["ReadData"]);
+        assert_eq!(auth.list_procedure_by_api(api_id).await.unwrap().len(), 2);
+
+        // new method is added and removed method is unlinked from roles then deleted
+        let report = auth.sync_procedure(api_id, &descriptor, &SyncOptions::default()).await.unwrap();
+        let procedures = auth.list_procedure_by_api(api_id).await.unwrap();
+        let role = auth.read_role(admin_role_id).await.unwrap();
+
+        assert_eq!(report.created, ["CreateData"]);
+        assert_eq!(report.deleted, ["DeleteData"]);
+        assert_eq!(procedures.len(), 2);
+        assert!(procedures.iter().any(|p| p.name == "CreateData"));
+        assert_eq!(role.procedures, [read_id]);
+
+        // synced API has nothing to change
+        let report = auth.sync_procedure(api_id, &descriptor, &SyncOptions::default()).await.unwrap();
+        assert!(report.created.is_empty());
+        assert!(report.deleted.is_empty());
+        assert_eq!(report.unchanged.len(), 2);
+
+        // service filter with a typo fails before any procedure is deleted
+        let options = SyncOptions { services: vec!["DataSevice".to_owned()], ..Default::default() };
+        let result = auth.sync_procedure(api_id, &descriptor, &options).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        assert_eq!(auth.list_procedure_by_api(api_id).await.unwrap().len(), 2);
+
+        // dry run list all dependent items of the API without deleting them
+        let role_profile_id = auth.create_role_profile(admin_role_id, "age", U16T, SingleOptional).await.unwrap();
+        let user_profile_id = auth.create_user_profile(admin_id, "age", U16(30)).await.unwrap();
+        let report = auth.delete_api_cascade(api_id, true).await.unwrap();
+        let api = auth.read_api(api_id).await.unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.apis, [api_id]);
+        assert_eq!(report.roles.len(), 2);
+        assert_eq!(report.procedures.len(), 2);
+        assert_eq!(report.role_accesses.len(), 2);
+        assert_eq!(report.user_roles, [(admin_id, admin_role_id)]);
+        assert_eq!(report.role_profiles, [role_profile_id]);
+        assert_eq!(api.procedures.len(), 2);
+
+        // delete admin role, its accesses, profiles and user links are removed but the user remains
+        let report = auth.delete_role_cascade(admin_role_id, false).await.unwrap();
+        let user = auth.read_user(admin_id).await.unwrap();
+
+        assert_eq!(report.roles, [admin_role_id]);
+        assert_eq!(report.role_accesses.len(), 1);
+        assert_eq!(report.user_roles, [(admin_id, admin_role_id)]);
+        assert!(auth.read_role(admin_role_id).await.is_err());
+        assert!(user.roles.is_empty());
+
+        // delete the API with remaining role and procedures, then the user with its profile
+        let report_api = auth.delete_api_cascade(api_id, false).await.unwrap();
+        let report_user = auth.delete_user_cascade(admin_id, false).await.unwrap();
+
+        assert_eq!(report_api.roles, [user_role_id]);
+        assert_eq!(report_api.procedures.len(), 2);
+        assert_eq!(report_user.users, [admin_id]);
+        assert_eq!(report_user.user_profiles, [user_profile_id]);
+        assert!(auth.read_api(api_id).await.is_err());
+        assert!(auth.read_user(admin_id).await.is_err());
+        assert!(auth.read_user(user_id).await.is_ok());
+
+        // failed cascade return the report of what was deleted before the failure
+        let (_, report) = auth.delete_user_cascade(admin_id, false).await.unwrap_err();
+        assert!(!report.dry_run);
+        assert!(report.users.is_empty());
+
+        auth_server.stop_server();
+    }
+
+    #[tokio::test]
+    async fn test_auth_token()
+    {
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        // resource API with "admin" role of "administrator" and "user" role of "username"
+        let api_id = auth.create_api(Uuid::new_v4(), "resource", "localhost:9002", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let admin_role_id = auth.create_role(Uuid::new_v4(), api_id, "admin", false, false, 900, 28800).await.unwrap();
+        let user_role_id = auth.create_role(Uuid::new_v4(), api_id, "user", true, false, 900, 28800).await.unwrap();
+        let admin_id = auth.create_user(Uuid::new_v4(), "administrator", "admin@mail.co", "", ADMIN_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, admin_role_id).await.unwrap();
+        let user_id = auth.create_user(Uuid::new_v4(), "username", "user@mail.co", "", USER_PASSWORD).await.unwrap();
+        auth.add_user_role(user_id, user_role_id).await.unwrap();
+
+        let ip1 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1));
+        let ip2 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 100));
+        let now = Utc::now();
+        let tokens = auth.create_auth_token(admin_id, now + chrono::Duration::hours(8), Some(ip1), 1).await.unwrap();
+        let (access_id, _, auth_token) = tokens[0].clone();
+
+        // ipv4 address mapped to ipv6 should match the stored ipv4 address
+        let token = auth.read_access_token(access_id).await.unwrap();
+        assert_eq!(token.ip_addr(), Some(ip1));
+        assert_eq!(token.ip_string(), "192.168.0.1");
+        assert!(token.ip_matches("::ffff:192.168.0.1".parse().unwrap()));
+        assert!(!token.ip_matches(ip2));
+
+        // role without ip lock allow any address, locked role only allow the token address
+        let role = auth.read_role(admin_role_id).await.unwrap();
+        assert!(ip_lock_allowed(&role, &token, ip2));
+        auth.update_role(admin_role_id, None, None, Some(true), None, None).await.unwrap();
+        let role = auth.read_role(admin_role_id).await.unwrap();
+        assert!(ip_lock_allowed(&role, &token, ip1));
+        assert!(!ip_lock_allowed(&role, &token, ip2));
+
+        // keep leave the address unchanged, set replace it and clear remove it
+        auth.update_access_token(access_id, None, IpUpdate::Keep).await.unwrap();
+        assert_eq!(auth.read_access_token(access_id).await.unwrap().ip_addr(), Some(ip1));
+        auth.update_auth_token(&auth_token, None, IpUpdate::Set(ip2)).await.unwrap();
+        assert_eq!(auth.read_access_token(access_id).await.unwrap().ip_addr(), Some(ip2));
+        auth.update_access_token(access_id, None, IpUpdate::Clear).await.unwrap();
+        let token = auth.read_access_token(access_id).await.unwrap();
+        assert_eq!(token.ip_addr(), None);
+        assert_eq!(token.ip_string(), "");
+        assert!(ip_lock_allowed(&role, &token, ip1));
+        assert!(ip_lock_allowed(&role, &token, ip2));
+        auth.delete_token_by_user(admin_id).await.unwrap();
+
+        // administrator has an expired session and a session with expiring and active tokens,
+        // user session is orphaned after its only role removed
+        let expired = auth.create_auth_token(admin_id, now - chrono::Duration::hours(1), Some(ip1), 1).await.unwrap();
+        let session = auth.create_auth_token(admin_id, now + chrono::Duration::hours(8), Some(ip2), 2).await.unwrap();
+        auth.update_access_token(session[0].0, Some(now + chrono::Duration::minutes(5)), IpUpdate::Keep).await.unwrap();
+        let orphaned = auth.create_auth_token(user_id, now + chrono::Duration::hours(8), None, 1).await.unwrap();
+        auth.remove_user_role(user_id, user_role_id).await.unwrap();
+
+        // audit all tokens, token without ip is not ip mismatched
+        let options = AuditOptions { expire_within: chrono::Duration::minutes(30), ip: Some(ip1) };
+        let report = auth.audit_token(&options).await.unwrap();
+
+        assert_eq!(report.groups.len(), 3);
+        assert_eq!(report.count(TokenStatus::Expired), 1);
+        assert_eq!(report.count(TokenStatus::ExpiringSoon), 1);
+        assert_eq!(report.count(TokenStatus::Active), 1);
+        assert_eq!(report.count(TokenStatus::Orphaned), 1);
+        assert_eq!(report.ip_mismatched().len(), 2);
+        assert!(report.ip_mismatched().iter().all(|t| t.token.auth_token == session[0].2));
+        let report_user = auth.audit_user_token(user_id, &options).await.unwrap();
+        assert_eq!(report_user.by_status(TokenStatus::Orphaned)[0].token.access_id, orphaned[0].0);
+
+        // revoke whole sessions when all of their tokens are selected
+        let revoked = auth.revoke_token(&report, &[TokenStatus::Expired, TokenStatus::Orphaned]).await.unwrap();
+
+        assert_eq!(revoked.auth_tokens.len(), 2);
+        assert!(revoked.auth_tokens.contains(&expired[0].2));
+        assert!(revoked.auth_tokens.contains(&orphaned[0].2));
+        assert_eq!(revoked.access_ids.len(), 2);
+        assert_eq!(auth.list_token_by_user(admin_id).await.unwrap().len(), 2);
+        assert!(auth.list_token_by_user(user_id).await.unwrap().is_empty());
+
+        // revoke only the selected access token when other tokens of the session remain
+        let report = auth.audit_token(&options).await.unwrap();
+        let revoked = auth.revoke_token(&report, &[TokenStatus::ExpiringSoon]).await.unwrap();
+        let tokens = auth.list_token_by_user(admin_id).await.unwrap();
+
+        assert!(revoked.auth_tokens.is_empty());
+        assert_eq!(revoked.access_ids, [session[0].0]);
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].access_id, session[1].0);
+
+        // administrator keep the remaining session token and also has role in other API,
+        // observer only has role in other API and username get its role back
+        let other_api_id = auth.create_api(Uuid::new_v4(), "other", "localhost:9003", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let other_role_id = auth.create_role(Uuid::new_v4(), other_api_id, "viewer", true, false, 900, 28800).await.unwrap();
+        let observer_id = auth.create_user(Uuid::new_v4(), "observer", "observer@mail.co", "", USER_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, other_role_id).await.unwrap();
+        auth.add_user_role(observer_id, other_role_id).await.unwrap();
+        auth.add_user_role(user_id, user_role_id).await.unwrap();
+        for id in [user_id, observer_id] {
+            auth.create_auth_token(id, now + chrono::Duration::hours(8), None, 1).await.unwrap();
+        }
+        let api_key = auth.read_api(api_id).await.unwrap().access_key;
+        let other_key = auth.read_api(other_api_id).await.unwrap().access_key;
+
+        // rotate resource API access key, role access keys should change too
+        let rotation = auth.rotate_api_key(api_id, TokenRevoke::Keep).await.unwrap();
+        let role = auth.read_role(admin_role_id).await.unwrap();
+        let role_rotation = rotation.roles.iter().find(|r| r.role_id == admin_role_id).unwrap();
+
+        assert_eq!(rotation.old_key, api_key);
+        assert_ne!(rotation.new_key, api_key);
+        assert_eq!(rotation.roles.len(), 2);
+        assert_ne!(role_rotation.new_key, role_rotation.old_key);
+        assert_eq!(role.access_key, role_rotation.new_key);
+        assert!(rotation.revoked_tokens.is_empty());
+        assert_eq!(auth.read_api(other_api_id).await.unwrap().access_key, other_key);
+
+        // skipping shared users revoke tokens of users only having roles in the rotated API
+        let rotation = auth.rotate_api_key(api_id, TokenRevoke::SkipShared).await.unwrap();
+
+        assert_eq!(rotation.revoked_tokens.len(), 1);
+        assert_eq!(rotation.skipped_users, [admin_id]);
+        assert!(auth.list_token_by_user(user_id).await.unwrap().is_empty());
+        assert_eq!(auth.list_token_by_user(admin_id).await.unwrap().len(), 1);
+
+        // revoke every token of users having role in the rotated API, users without the role keep their tokens
+        let rotation = auth.rotate_api_key(api_id, TokenRevoke::All).await.unwrap();
+
+        assert_eq!(rotation.revoked_tokens, [session[1].0]);
+        assert!(rotation.skipped_users.is_empty());
+        assert!(auth.list_token_by_user(admin_id).await.unwrap().is_empty());
+        assert_eq!(auth.list_token_by_user(observer_id).await.unwrap().len(), 1);
+
+        auth_server.stop_server();
+    }
+
     #[tokio::test]
     async fn test_resource()
     {
         unsafe { std::env::set_var("RUST_BACKTRACE", "1"); }
+        let _lock = RESOURCE_SERVER.lock().await;
 
         // start resource server
         let resource_server = TestServer::new(TestServerKind::Resource);
@@ -572,4 +933,1180 @@ mod tests {
         resource_server.stop_server();
     }
 
+    // tcp proxy to the target server, aborting the returned task close the listener and all of its connections
+    async fn start_proxy(target: &str) -> (std::string::String, tokio::task::JoinHandle<()>)
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let target = target.trim_start_matches("http://").trim_start_matches("//").to_owned();
+        let task = tokio::spawn(async move {
+            let mut connections = tokio::task::JoinSet::new();
+            while let Ok((mut inbound, _)) = listener.accept().await {
+                let target = target.clone();
+                connections.spawn(async move {
+                    if let Ok(mut outbound) = tokio::net::TcpStream::connect(target).await {
+                        let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+                    }
+                });
+            }
+        });
+        (address, task)
+    }
+
+    // wait until health check update the endpoint status
+    async fn wait_endpoints(resource: &Resource, healthy: &[bool])
+    {
+        for _ in 0..50 {
+            if resource.endpoints().iter().map(|e| e.healthy).collect::<Vec<bool>>() == healthy {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        panic!("endpoint status is not updated: {:?}", resource.endpoints());
+    }
+
+    // application code depend on api trait instead of resource client
+    async fn model_exists(api: &impl ModelApi, id: Uuid) -> bool {
+        api.read_model(id).await.is_ok()
+    }
+
+    // in memory model service, model configurations and tags are not supported
+    #[derive(Default)]
+    struct FakeModels {
+        models: std::sync::Mutex<Vec<ModelSchema>>
+    }
+
+    impl FakeModels {
+        fn filter(&self, f: impl Fn(&ModelSchema) -> bool) -> Result<Vec<ModelSchema>, Status> {
+            Ok(self.models.lock().unwrap().iter().filter(|m| f(m)).cloned().collect())
+        }
+    }
+
+    impl ModelApi for FakeModels {
+        async fn read_model(&self, id: Uuid) -> Result<ModelSchema, Status> {
+            self.filter(|m| m.id == id)?.pop().ok_or(Status::not_found("model not found"))
+        }
+        async fn list_model_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ModelSchema>, Status> {
+            self.filter(|m| ids.contains(&m.id))
+        }
+        async fn list_model_by_type(&self, _type_id: Uuid) -> Result<Vec<ModelSchema>, Status> {
+            Err(Status::unimplemented("model type is not supported"))
+        }
+        async fn list_model_by_name(&self, name: &str) -> Result<Vec<ModelSchema>, Status> {
+            self.filter(|m| m.name.contains(name))
+        }
+        async fn list_model_by_category(&self, category: &str) -> Result<Vec<ModelSchema>, Status> {
+            self.filter(|m| m.category == category)
+        }
+        async fn list_model_option(&self, _type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>) -> Result<Vec<ModelSchema>, Status> {
+            self.filter(|m| name.is_none_or(|n| m.name.contains(n)) && category.is_none_or(|c| m.category == c))
+        }
+        async fn create_model(&self, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>) -> Result<Uuid, Status> {
+            let model = ModelSchema {
+                id,
+                category: category.to_owned(),
+                name: name.to_owned(),
+                description: description.unwrap_or_default().to_owned(),
+                data_type: data_type.to_vec(),
+                configs: Vec::new(),
+                tags: Vec::new()
+            };
+            self.models.lock().unwrap().push(model);
+            Ok(id)
+        }
+        async fn update_model(&self, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>) -> Result<(), Status> {
+            let mut models = self.models.lock().unwrap();
+            let model = models.iter_mut().find(|m| m.id == id).ok_or(Status::not_found("model not found"))?;
+            if let Some(value) = data_type { model.data_type = value.to_vec(); }
+            if let Some(value) = category { model.category = value.to_owned(); }
+            if let Some(value) = name { model.name = value.to_owned(); }
+            if let Some(value) = description { model.description = value.to_owned(); }
+            Ok(())
+        }
+        async fn delete_model(&self, id: Uuid) -> Result<(), Status> {
+            let mut models = self.models.lock().unwrap();
+            let index = models.iter().position(|m| m.id == id).ok_or(Status::not_found("model not found"))?;
+            models.remove(index);
+            Ok(())
+        }
+        async fn read_model_config(&self, _id: i32) -> Result<ModelConfigSchema, Status> {
+            Err(Status::unimplemented("model config is not supported"))
+        }
+        async fn list_model_config_by_model(&self, _model_id: Uuid) -> Result<Vec<ModelConfigSchema>, Status> {
+            Err(Status::unimplemented("model config is not supported"))
+        }
+        async fn create_model_config(&self, _model_id: Uuid, _index: i32, _name: &str, _value: DataValue, _category: &str) -> Result<i32, Status> {
+            Err(Status::unimplemented("model config is not supported"))
+        }
+        async fn update_model_config(&self, _id: i32, _name: Option<&str>, _value: Option<DataValue>, _category: Option<&str>) -> Result<(), Status> {
+            Err(Status::unimplemented("model config is not supported"))
+        }
+        async fn delete_model_config(&self, _id: i32) -> Result<(), Status> {
+            Err(Status::unimplemented("model config is not supported"))
+        }
+        async fn read_tag(&self, _model_id: Uuid, _tag: i16) -> Result<TagSchema, Status> {
+            Err(Status::unimplemented("tag is not supported"))
+        }
+        async fn list_tag_by_model(&self, _model_id: Uuid) -> Result<Vec<TagSchema>, Status> {
+            Err(Status::unimplemented("tag is not supported"))
+        }
+        async fn create_tag(&self, _model_id: Uuid, _tag: i16, _name: &str, _members: &[i16]) -> Result<(), Status> {
+            Err(Status::unimplemented("tag is not supported"))
+        }
+        async fn update_tag(&self, _model_id: Uuid, _tag: i16, _name: Option<&str>, _members: Option<&[i16]>) -> Result<(), Status> {
+            Err(Status::unimplemented("tag is not supported"))
+        }
+        async fn delete_tag(&self, _model_id: Uuid, _tag: i16) -> Result<(), Status> {
+            Err(Status::unimplemented("tag is not supported"))
+        }
+    }
+
+    // decorator which count every call before passing it to the wrapped api
+    struct CountedModels<A> {
+        inner: A,
+        calls: std::sync::atomic::AtomicUsize
+    }
+
+    impl<A: ModelApi> CountedModels<A> {
+        fn new(inner: A) -> Self {
+            CountedModels { inner, calls: std::sync::atomic::AtomicUsize::new(0) }
+        }
+        fn count(&self) {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    impl<A: ModelApi> ModelApi for CountedModels<A> {
+        async fn read_model(&self, id: Uuid) -> Result<ModelSchema, Status> {
+            self.count();
+            self.inner.read_model(id).await
+        }
+        async fn list_model_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ModelSchema>, Status> {
+            self.count();
+            self.inner.list_model_by_ids(ids).await
+        }
+        async fn list_model_by_type(&self, type_id: Uuid) -> Result<Vec<ModelSchema>, Status> {
+            self.count();
+            self.inner.list_model_by_type(type_id).await
+        }
+        async fn list_model_by_name(&self, name: &str) -> Result<Vec<ModelSchema>, Status> {
+            self.count();
+            self.inner.list_model_by_name(name).await
+        }
+        async fn list_model_by_category(&self, category: &str) -> Result<Vec<ModelSchema>, Status> {
+            self.count();
+            self.inner.list_model_by_category(category).await
+        }
+        async fn list_model_option(&self, type_id: Option<Uuid>, name: Option<&str>, category: Option<&str>) -> Result<Vec<ModelSchema>, Status> {
+            self.count();
+            self.inner.list_model_option(type_id, name, category).await
+        }
+        async fn create_model(&self, id: Uuid, data_type: &[DataType], category: &str, name: &str, description: Option<&str>) -> Result<Uuid, Status> {
+            self.count();
+            self.inner.create_model(id, data_type, category, name, description).await
+        }
+        async fn update_model(&self, id: Uuid, data_type: Option<&[DataType]>, category: Option<&str>, name: Option<&str>, description: Option<&str>) -> Result<(), Status> {
+            self.count();
+            self.inner.update_model(id, data_type, category, name, description).await
+        }
+        async fn delete_model(&self, id: Uuid) -> Result<(), Status> {
+            self.count();
+            self.inner.delete_model(id).await
+        }
+        async fn read_model_config(&self, id: i32) -> Result<ModelConfigSchema, Status> {
+            self.count();
+            self.inner.read_model_config(id).await
+        }
+        async fn list_model_config_by_model(&self, model_id: Uuid) -> Result<Vec<ModelConfigSchema>, Status> {
+            self.count();
+            self.inner.list_model_config_by_model(model_id).await
+        }
+        async fn create_model_config(&self, model_id: Uuid, index: i32, name: &str, value: DataValue, category: &str) -> Result<i32, Status> {
+            self.count();
+            self.inner.create_model_config(model_id, index, name, value, category).await
+        }
+        async fn update_model_config(&self, id: i32, name: Option<&str>, value: Option<DataValue>, category: Option<&str>) -> Result<(), Status> {
+            self.count();
+            self.inner.update_model_config(id, name, value, category).await
+        }
+        async fn delete_model_config(&self, id: i32) -> Result<(), Status> {
+            self.count();
+            self.inner.delete_model_config(id).await
+        }
+        async fn read_tag(&self, model_id: Uuid, tag: i16) -> Result<TagSchema, Status> {
+            self.count();
+            self.inner.read_tag(model_id, tag).await
+        }
+        async fn list_tag_by_model(&self, model_id: Uuid) -> Result<Vec<TagSchema>, Status> {
+            self.count();
+            self.inner.list_tag_by_model(model_id).await
+        }
+        async fn create_tag(&self, model_id: Uuid, tag: i16, name: &str, members: &[i16]) -> Result<(), Status> {
+            self.count();
+            self.inner.create_tag(model_id, tag, name, members).await
+        }
+        async fn update_tag(&self, model_id: Uuid, tag: i16, name: Option<&str>, members: Option<&[i16]>) -> Result<(), Status> {
+            self.count();
+            self.inner.update_tag(model_id, tag, name, members).await
+        }
+        async fn delete_tag(&self, model_id: Uuid, tag: i16) -> Result<(), Status> {
+            self.count();
+            self.inner.delete_tag(model_id, tag).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_model_api()
+    {
+        // code depending on model api run against fake service wrapped by a decorator without any server
+        let api = CountedModels::new(FakeModels::default());
+        let model_id = api.create_model(Uuid::new_v4(), &[F32T, F32T], "UPLINK", "speed and direction", None).await.unwrap();
+        assert!(model_exists(&api, model_id).await);
+        assert_eq!(api.list_model_by_category("UPLINK").await.unwrap().len(), 1);
+        assert_eq!(api.list_model_option(None, Some("speed"), Some("DOWNLINK")).await.unwrap().len(), 0);
+
+        api.update_model(model_id, None, None, Some("speed"), Some("speed only")).await.unwrap();
+        let model = api.read_model(model_id).await.unwrap();
+        assert_eq!(model.name, "speed");
+        assert_eq!(model.description, "speed only");
+        assert_eq!(model.data_type, [F32T, F32T]);
+
+        api.delete_model(model_id).await.unwrap();
+        assert!(!model_exists(&api, model_id).await);
+        assert_eq!(api.delete_model(model_id).await.unwrap_err().code(), tonic::Code::NotFound);
+        assert_eq!(api.read_tag(model_id, 0).await.unwrap_err().code(), tonic::Code::Unimplemented);
+
+        // every call passed through the decorator
+        assert_eq!(api.calls.load(std::sync::atomic::Ordering::Relaxed), 10);
+    }
+
+    #[tokio::test]
+    async fn test_resource_balance()
+    {
+        use rmcs_api_client::resource::BalanceConfig;
+
+        // start resource server, two proxies to the server act as two resource server endpoints
+        let _lock = RESOURCE_SERVER.lock().await;
+        let resource_server = TestServer::new(TestServerKind::Resource);
+        resource_server.truncate_tables().await.unwrap();
+        resource_server.start_server();
+        let (address1, proxy1) = start_proxy(&resource_server.address).await;
+        let (address2, proxy2) = start_proxy(&resource_server.address).await;
+
+        let config = BalanceConfig { health_interval: std::time::Duration::from_millis(200), ..Default::default() };
+        let resource = Resource::new_balanced(&[&address1, &address2], config).await;
+        let endpoints = resource.endpoints();
+        assert_eq!(endpoints.iter().map(|e| e.address.as_str()).collect::<Vec<&str>>(), [address1.as_str(), address2.as_str()]);
+        assert!(endpoints.iter().all(|e| e.healthy));
+        let model_id = resource.create_model(Uuid::new_v4(), &[F32T], "UPLINK", "balanced", None).await.unwrap();
+        for _ in 0..10 {
+            assert_eq!(resource.read_model(model_id).await.unwrap().name, "balanced");
+        }
+
+        // first endpoint stopped in the middle of calls, calls are sent to the remaining endpoint
+        proxy1.abort();
+        for _ in 0..10 {
+            assert_eq!(resource.read_model(model_id).await.unwrap().name, "balanced");
+        }
+        wait_endpoints(&resource, &[false, true]).await;
+        // mutation is sent once to the healthy endpoint after the stopped endpoint is ejected
+        resource.update_model(model_id, None, None, None, Some("failed over")).await.unwrap();
+        assert_eq!(resource.read_model(model_id).await.unwrap().description, "failed over");
+
+        // no healthy endpoint left, calls fail immediately instead of waiting for the timeout
+        proxy2.abort();
+        wait_endpoints(&resource, &[false, false]).await;
+        let start = std::time::Instant::now();
+        let status = resource.read_model(model_id).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+        let status = resource.delete_model(model_id).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+
+        resource_server.stop_server();
+    }
+
+    #[tokio::test]
+    async fn test_connect_error()
+    {
+        // invalid address and unreachable server should return error instead of panic
+        let result = Auth::try_new("invalid address").await;
+        assert!(matches!(result, Err(Error::InvalidAddress(_))));
+        let result = Resource::try_new("http://127.0.0.1:1").await;
+        assert!(matches!(result, Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn test_settings()
+    {
+        use rmcs_api_client::settings::*;
+
+        // write TOML and .env files, .env value must override TOML value
+        let dir = std::env::temp_dir().join(format!("rmcs_settings_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let toml_file = dir.join("client.toml");
+        let env_file = dir.join(".env");
+        std::fs::write(&toml_file, "[server]\naddress_auth = \"//127.0.0.1:9001\"\naddress_resource = \"//127.0.0.1:9002\"\n\n[retry]\nmax_attempts = 5\nbackoff_ms = 200\n").unwrap();
+        std::fs::write(&env_file, "SERVER_ADDRESS_RESOURCE=//127.0.0.1:9102\nCONNECT_TIMEOUT_MS=1500\n").unwrap();
+        let mut settings = Settings::from_toml(&std::fs::read_to_string(&toml_file).unwrap()).unwrap();
+        settings.merge(Settings::from_dotenv(&env_file).unwrap());
+
+        let auth_config = settings.auth_config().unwrap();
+        let resource_config = settings.resource_config().unwrap();
+        assert_eq!(auth_config.address, "http://127.0.0.1:9001");
+        assert_eq!(resource_config.address, "http://127.0.0.1:9102");
+        assert_eq!(resource_config.connect_timeout, Some(std::time::Duration::from_millis(1500)));
+        assert_eq!(settings.retry_max_attempts().unwrap(), Some(5));
+        assert_eq!(settings.retry_backoff().unwrap(), Some(std::time::Duration::from_millis(200)));
+
+        // missing and invalid keys must return configuration error naming the key
+        let error = settings.credentials().unwrap_err();
+        assert!(matches!(&error, Error::Config(message) if message.contains(AUTH_USERNAME)));
+        settings.set(REQUEST_TIMEOUT_MS, "ten");
+        assert!(matches!(settings.auth_config(), Err(Error::Config(message)) if message.contains(REQUEST_TIMEOUT_MS)));
+        assert!(matches!(Settings::default().auth_config(), Err(Error::Config(message)) if message.contains(SERVER_ADDRESS_AUTH)));
+
+        // empty value does not override value from other source
+        let mut empty = Settings::default();
+        empty.set(SERVER_ADDRESS_RESOURCE, "");
+        settings.merge(empty);
+        assert_eq!(settings.get(SERVER_ADDRESS_RESOURCE), Some("//127.0.0.1:9102"));
+
+        // shared server address is used when server specific address is not set
+        let mut shared = Settings::default();
+        shared.set(SERVER_ADDRESS, "//127.0.0.1:9000");
+        assert_eq!(shared.auth_config().unwrap().address, "http://127.0.0.1:9000");
+        assert_eq!(shared.resource_config().unwrap().address, "http://127.0.0.1:9000");
+        shared.set(SERVER_ADDRESS_AUTH, "//127.0.0.1:9001");
+        assert_eq!(shared.auth_config().unwrap().address, "http://127.0.0.1:9001");
+        assert_eq!(shared.resource_config().unwrap().address, "http://127.0.0.1:9000");
+
+        // plain text password over non TLS channel must be allowed explicitly
+        assert!(!shared.auth_config().unwrap().plaintext_password);
+        shared.set(PLAINTEXT_PASSWORD, "true");
+        assert!(shared.auth_config().unwrap().plaintext_password);
+
+        // file loader read both files
+        let settings = Settings::load(Some(&env_file), Some(&toml_file)).unwrap();
+        assert_eq!(settings.get(RETRY_MAX_ATTEMPTS), Some("5"));
+        assert!(settings.get(SERVER_ADDRESS_RESOURCE).is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_config_tls()
+    {
+        use tonic::transport::{ServerTlsConfig, Identity, Certificate};
+
+        // start local TLS server requiring client certificate, it serves only reflection service
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let ca = include_bytes!("tls/ca.pem");
+        let tls = ServerTlsConfig::new()
+            .identity(Identity::from_pem(include_bytes!("tls/server.pem"), include_bytes!("tls/server.key")))
+            .client_ca_root(Certificate::from_pem(ca));
+        let reflection = tonic_reflection::server::Builder::configure().build_v1().unwrap();
+        let server = Server::builder().tls_config(tls).unwrap().add_service(reflection);
+        tokio::spawn(server.serve_with_incoming(TcpIncoming::from(listener)));
+
+        // successful mutual TLS handshake must give unimplemented status for auth and resource services
+        let config = ClientConfig::new(&format!("https://{}", address))
+            .ca_certificate(ca)
+            .identity(include_bytes!("tls/client.pem"), include_bytes!("tls/client.key"))
+            .domain("localhost")
+            .connect_timeout(std::time::Duration::from_secs(5))
+            .timeout(std::time::Duration::from_secs(5))
+            .keep_alive(std::time::Duration::from_secs(30), std::time::Duration::from_secs(10), true)
+            .window_size(1 << 20, 1 << 21);
+        let auth = config.auth().await.unwrap();
+        let status = auth.read_api(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+        let resource = config.resource().await.unwrap();
+        let status = resource.read_model(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+        // resource address without scheme is connected with TLS of the client config
+        let resource_config = ClientConfig { address: address.clone(), ..config.clone() };
+        let client = RmcsClient::connect_with_resource(config.clone(), Some(resource_config)).await.unwrap();
+        let status = client.resource_public().unwrap().read_model(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+        // connection without client certificate or with unknown ca must fail
+        async fn handshake_error(config: ClientConfig) -> bool {
+            match config.auth().await {
+                Ok(auth) => auth.read_api(Uuid::new_v4()).await.is_err_and(|e| e.code() != tonic::Code::Unimplemented),
+                Err(_) => true
+            }
+        }
+        let config_no_identity = ClientConfig::new(&format!("https://{}", address)).ca_certificate(ca).domain("localhost");
+        assert!(handshake_error(config_no_identity).await);
+        let config_unknown_ca = ClientConfig { ca_certificates: Vec::new(), ..config };
+        assert!(handshake_error(config_unknown_ca).await);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy()
+    {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::time::Duration;
+        use tonic::{Code, Status};
+        use rmcs_api_client::retry::already_exists;
+
+        // exponential backoff delay bounded by maximum backoff
+        let policy = RetryPolicy::new(4).backoff(Duration::from_millis(10), Duration::from_millis(30)).jitter(false);
+        assert_eq!(policy.backoff_delay(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff_delay(2), Duration::from_millis(20));
+        assert_eq!(policy.backoff_delay(3), Duration::from_millis(30));
+        assert_eq!(policy.backoff_delay(u32::MAX), Duration::from_millis(30));
+
+        // invalid multiplier keep the backoff constant and invalid field value does not panic
+        let constant = policy.clone().multiplier(-2.0);
+        assert_eq!(constant.backoff_delay(3), Duration::from_millis(10));
+        let invalid = RetryPolicy { multiplier: f64::NAN, ..policy.clone() };
+        assert!(invalid.backoff_delay(100) <= Duration::from_millis(30));
+        assert!(policy.is_retryable(&Status::unavailable("")));
+        assert!(!policy.is_retryable(&Status::invalid_argument("")));
+
+        // mutation retried after unavailable then already exists error is treated as success by the guard
+        let channel = tonic::transport::Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        let resource = Resource::new_with_channel(channel).with_retry(policy.clone());
+        let attempts = AtomicU32::new(0);
+        let result = resource.call_idempotent(|| async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(Status::unavailable("connection reset")),
+                _ => Err(Status::already_exists("data already exists"))
+            }
+        }, already_exists(())).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // non retryable error returned immediately and retryable error stop after max attempts
+        attempts.store(0, Ordering::SeqCst);
+        let result: Result<(), Status> = resource.call_idempotent(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Status::already_exists("data already exists"))
+        }, already_exists(())).await;
+        assert_eq!(result.unwrap_err().code(), Code::AlreadyExists);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        attempts.store(0, Ordering::SeqCst);
+        let result: Result<(), Status> = resource.call_idempotent(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Status::unavailable("connection reset"))
+        }, already_exists(())).await;
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_timeout()
+    {
+        use std::time::{Duration, Instant};
+
+        // server accepting connection but never responding
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+        let channel = tonic::transport::Endpoint::from_shared(address).unwrap().connect_lazy();
+        let resource = Resource::new_with_channel(channel)
+            .with_timeout(Some(Duration::from_millis(100)))
+            .with_long_timeout(Some(Duration::from_millis(400)));
+
+        // metadata read use default timeout and reported as timeout error
+        let start = Instant::now();
+        let status = resource.read_model(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+        assert!(start.elapsed() < Duration::from_millis(400));
+        assert!(matches!(Error::from(status), Error::Timeout(_)));
+
+        // data listing over a time window use long timeout
+        let start = Instant::now();
+        let status = resource.list_data_by_range(Uuid::new_v4(), Uuid::new_v4(), Utc::now() - chrono::Duration::days(1), Utc::now(), None).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+        assert!(start.elapsed() >= Duration::from_millis(400));
+
+        // per call timeout override the long timeout
+        let start = Instant::now();
+        let status = resource.with_options(CallOptions::new().timeout(Duration::from_millis(50)))
+            .list_data_by_range(Uuid::new_v4(), Uuid::new_v4(), Utc::now() - chrono::Duration::days(1), Utc::now(), None).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+        assert!(start.elapsed() < Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_resource_token()
+    {
+        // token pair set on a resource must be shared with all of its clones
+        let channel = tonic::transport::Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        let resource = Resource::new_with_channel(channel).with_token("access", "refresh");
+        let resource_clone = resource.clone();
+        resource.set_token("new_access", "new_refresh");
+        assert_eq!(resource_clone.token(), ("new_access".to_owned(), "new_refresh".to_owned()));
+        // refresh without auth client should return error
+        assert!(resource_clone.refresh().await.is_err());
+        // request scoped options must not change token of the base resource
+        let resource_scoped = resource.with_options(CallOptions::new().token("scoped").timeout(std::time::Duration::from_secs(5)));
+        assert_eq!(resource_scoped.token(), resource.token());
+    }
+
+    #[tokio::test]
+    async fn test_call_options()
+    {
+        // token and metadata of scoped resource are sent instead of the base resource token
+        let (address, requests) = start_token_server("rejected").await;
+        let resource = Resource::new(&address).await.with_token("base", "refresh");
+        let options = CallOptions::new().token("scoped").metadata("X-Request-Id", "42").timeout(std::time::Duration::from_secs(5));
+        let resource_scoped = resource.with_options(options);
+        resource_scoped.delete_model(Uuid::new_v4()).await.unwrap();
+        resource.delete_model(Uuid::new_v4()).await.unwrap();
+
+        let metadata = requests.lock().unwrap().clone();
+        let authorization = |m: &MetadataMap| m.get("authorization").unwrap().to_str().unwrap().to_owned();
+        assert_eq!(metadata.len(), 2);
+        assert!(authorization(&metadata[0]).ends_with("scoped"));
+        assert_eq!(metadata[0].get("x-request-id").unwrap(), "42");
+        assert!(authorization(&metadata[1]).ends_with("base"));
+        assert!(metadata[1].get("x-request-id").is_none());
+
+        // concurrent calls with different options keep their own token
+        let resource_user1 = resource.with_options(CallOptions::new().token("user1"));
+        let resource_user2 = resource.with_options(CallOptions::new().token("user2"));
+        let (result1, result2) = tokio::join!(resource_user1.delete_model(Uuid::new_v4()), resource_user2.delete_model(Uuid::new_v4()));
+        result1.unwrap();
+        result2.unwrap();
+        let mut tokens: Vec<std::string::String> = requests.lock().unwrap()[2..].iter().map(authorization).collect();
+        tokens.sort();
+        assert!(tokens[0].ends_with("user1") && tokens[1].ends_with("user2"));
+
+        // invalid metadata is rejected before the request is sent
+        let resource_invalid = resource.with_options(CallOptions::new().metadata("invalid key", "42"));
+        let status = resource_invalid.delete_model(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(requests.lock().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_auth_login()
+    {
+        use std::time::{Duration, Instant};
+        use rmcs_auth_api::auth::{UserLoginResponse, AccessTokenMap};
+
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        // access tokens of the admin role expire 2 seconds after issued
+        let api_id = auth.create_api(Uuid::new_v4(), "resource", "localhost:9002", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let role_id = auth.create_role(Uuid::new_v4(), api_id, "admin", false, false, 2, 28800).await.unwrap();
+        let admin_id = auth.create_user(Uuid::new_v4(), "administrator", "admin@mail.co", "", ADMIN_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, role_id).await.unwrap();
+
+        // login create a resource client with its token pair for every api of the user
+        let mut session = Session::login(&auth, "administrator", ADMIN_PASSWORD).await.unwrap();
+        assert_eq!(session.user_id(), admin_id);
+        assert_eq!(session.apis().len(), 1);
+        assert!(session.resource(api_id).is_some());
+        assert!(session.resource_by_name("unknown").is_none());
+        let resource = session.resource_by_name("resource").unwrap();
+        let (access_token, refresh_token) = resource.token();
+
+        // access token is signed with the access key of the API, verification with another key should failed
+        let access_key = auth.read_api(api_id).await.unwrap().access_key;
+        let claims = AccessClaims::verify(&access_token, &access_key).unwrap();
+        assert_eq!(claims, AccessClaims::decode(&access_token).unwrap());
+        assert_eq!(claims.user_id, admin_id);
+        assert_eq!(claims.expire - claims.issued_at, chrono::Duration::seconds(2));
+        assert!(claims.expire_within(chrono::Duration::seconds(2)));
+        assert!(matches!(AccessClaims::verify(&access_token, &generate_access_key()), Err(Error::InvalidToken(_))));
+        assert!(matches!(AccessClaims::decode("invalid token"), Err(Error::InvalidToken(_))));
+
+        // background task refresh the token pair before the access token expire
+        session.start_refresh(Duration::from_secs(1));
+        let start = Instant::now();
+        while resource.token().0 == access_token && start.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        let (new_access_token, new_refresh_token) = resource.token();
+        assert_ne!(new_access_token, access_token);
+        assert_ne!(new_refresh_token, refresh_token);
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert!(!AccessClaims::decode(&new_access_token).unwrap().is_expired());
+
+        // logout remove the tokens of the session so they can not be refreshed anymore
+        session.logout().await.unwrap();
+        assert!(resource.refresh().await.is_err());
+
+        // login attach access token of the user to the resolved resource API
+        let registry = ApiRegistry::login(&auth, "RESOURCE", "administrator", ADMIN_PASSWORD).await.unwrap();
+        let apis = registry.apis();
+        assert_eq!(apis.len(), 1);
+        assert_eq!(apis[0].id, api_id);
+        assert_eq!(apis[0].address, "localhost:9002");
+        let resource = registry.resource("resource").await.unwrap();
+        let token = resource.token();
+        assert!(!token.0.is_empty());
+        assert!(matches!(registry.resource("Unregistered").await, Err(Error::NotFound(_))));
+
+        // api registered later is resolved on demand, changed address is reconnected with the same token
+        let other_id = auth.create_api(Uuid::new_v4(), "other", "localhost:9003", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        assert!(registry.resource_by_id(other_id).await.is_ok());
+        auth.update_api(api_id, None, Some("localhost:9102"), None, None, None, None).await.unwrap();
+        let apis = registry.resolve().await.unwrap();
+        let api = apis.iter().find(|a| a.id == api_id).unwrap();
+        assert_eq!(api.address, "localhost:9102");
+        assert_eq!(api.resource.token(), token);
+
+        // login response with invalid api id is rejected
+        let login = UserLoginResponse {
+            access_tokens: vec![AccessTokenMap { api_id: vec![1, 2, 3], ..Default::default() }],
+            ..Default::default()
+        };
+        let result = ApiRegistry::new(&auth, "RESOURCE").with_login(&login);
+        assert!(matches!(result, Err(Error::Parse(_))));
+
+        // resource server treat the access token from login as an expired token
+        let login = auth.user_login("administrator", ADMIN_PASSWORD).await.unwrap();
+        let token = login.access_tokens.iter().find(|t| t.api_id == api_id.as_bytes()).unwrap();
+        let (address, requests) = start_token_server(&token.access_token).await;
+        let resource = Resource::new(&address).await
+            .with_token(&token.access_token, &token.refresh_token)
+            .with_auth(&auth, api_id);
+        let resource_clone = resource.clone();
+
+        // unauthenticated call refresh the token pair and is sent again with the new access token
+        resource.delete_model(Uuid::new_v4()).await.unwrap();
+        let (access_token, refresh_token) = resource_clone.token();
+        assert_ne!(access_token, token.access_token);
+        assert_ne!(refresh_token, token.refresh_token);
+        let tokens: Vec<std::string::String> = requests.lock().unwrap().iter()
+            .map(|m| m.get("authorization").unwrap().to_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens[0].ends_with(&token.access_token));
+        assert!(tokens[1].ends_with(&access_token));
+
+        // client without auth client return the unauthenticated status without refreshing
+        let resource = Resource::new(&address).await.with_token(&token.access_token, &token.refresh_token);
+        let status = resource.delete_model(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert_eq!(resource.token().0, token.access_token);
+
+        // resource API points to the first fake server, administrator also has role in other API without address
+        let (address1, requests1) = start_token_server("rejected").await;
+        let (address2, requests2) = start_token_server("rejected").await;
+        auth.update_api(api_id, None, Some(&address1), None, None, None, None).await.unwrap();
+        auth.update_api(other_id, None, Some(""), None, None, None, None).await.unwrap();
+        let other_role_id = auth.create_role(Uuid::new_v4(), other_id, "viewer", true, false, 900, 28800).await.unwrap();
+        auth.add_user_role(admin_id, other_role_id).await.unwrap();
+
+        // login and logout through unified client, resource clients are connected for every api of the user
+        let resource_config = ClientConfig::new(&address2);
+        let mut client = RmcsClient::connect_with_resource(ClientConfig::new(&auth_server.address), Some(resource_config)).await.unwrap();
+        assert!(matches!(client.resource("resource"), Err(Error::Unauthenticated(_))));
+        client.login("administrator", ADMIN_PASSWORD).await.unwrap();
+        assert_eq!(client.user_id(), Some(admin_id));
+        assert_eq!(client.apis().len(), 2);
+
+        // api address from auth server is used, resource config address only for api without address
+        client.resource("resource").unwrap().delete_model(Uuid::new_v4()).await.unwrap();
+        assert_eq!(requests1.lock().unwrap().len(), 1);
+        assert_eq!(requests2.lock().unwrap().len(), 0);
+        client.resource_by_id(other_id).unwrap().delete_model(Uuid::new_v4()).await.unwrap();
+        assert_eq!(requests1.lock().unwrap().len(), 1);
+        assert_eq!(requests2.lock().unwrap().len(), 1);
+
+        // password is not sent over non TLS channel unless explicitly allowed
+        let status = client.auth().update_user(admin_id, None, None, None, Some(ADMIN_PASSWORD)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        client.auth().update_user(admin_id, None, Some("admin@mail.com"), None, None).await.unwrap();
+
+        client.logout().await.unwrap();
+        assert!(!client.is_logged_in());
+        assert!(matches!(client.resource("resource"), Err(Error::Unauthenticated(_))));
+        let config = ClientConfig::new(&auth_server.address).plaintext_password(true);
+        let mut client = RmcsClient::connect(config).await.unwrap();
+        client.auth().update_user(admin_id, None, None, None, Some(ADMIN_PASSWORD)).await.unwrap();
+
+        // failed logout keep the session
+        client.login("administrator", ADMIN_PASSWORD).await.unwrap();
+        auth_server.stop_server();
+        assert!(client.logout().await.is_err());
+        assert!(client.is_logged_in());
+    }
+
+    #[test]
+    fn test_manifest()
+    {
+        let manifest_toml = r#"
+            [[apis]]
+            name = "resource"
+            address = "localhost:9002"
+            category = "RESOURCE"
+            password = "Api_pa55w0rd"
+            procedures = [{ name = "read_model" }, { name = "create_model" }]
+
+            [[apis.roles]]
+            name = "admin"
+            access_duration = 900
+            refresh_duration = 43200
+            procedures = ["read_model", "create_model"]
+            profiles = [{ name = "age", value_type = "U16", mode = "SingleOptional" }]
+
+            [[users]]
+            name = "administrator"
+            password = "Adm1n_P4s5w0rd"
+            roles = [{ api = "resource", role = "admin" }]
+        "#;
+        let manifest_json = r#"{
+            "apis": [{
+                "name": "resource", "address": "localhost:9002", "category": "RESOURCE", "password": "Api_pa55w0rd",
+                "procedures": [{ "name": "read_model" }, { "name": "create_model" }],
+                "roles": [{
+                    "name": "admin", "access_duration": 900, "refresh_duration": 43200,
+                    "procedures": ["read_model", "create_model"],
+                    "profiles": [{ "name": "age", "value_type": "U16", "mode": "SingleOptional" }]
+                }]
+            }],
+            "users": [{ "name": "administrator", "password": "Adm1n_P4s5w0rd", "roles": [{ "api": "resource", "role": "admin" }] }]
+        }"#;
+
+        // toml and json manifest should describe the same configuration
+        let manifest = Manifest::from_toml(manifest_toml).unwrap();
+        assert_eq!(manifest, Manifest::from_json(manifest_json).unwrap());
+        assert_eq!(manifest.apis[0].roles[0].procedures.len(), 2);
+        assert_eq!(manifest.users[0].roles[0].role, "admin");
+        assert!(!manifest.apis[0].roles[0].ip_lock);
+
+        // manifest with missing required field should error
+        let result = Manifest::from_toml("[[apis]]\nname = \"resource\"");
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[tokio::test]
+    async fn test_manifest_apply()
+    {
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        let mut manifest = Manifest::from_toml(r#"
+            [[apis]]
+            name = "resource"
+            address = "localhost:9002"
+            category = "RESOURCE"
+            password = "Api_pa55w0rd"
+            procedures = [{ name = "read_model" }, { name = "create_model" }]
+
+            [[apis.roles]]
+            name = "admin"
+            access_duration = 900
+            refresh_duration = 43200
+            procedures = ["read_model", "create_model"]
+            profiles = [{ name = "age", value_type = "U16", mode = "SingleOptional" }]
+
+            [[users]]
+            name = "administrator"
+            password = "Adm1n_P4s5w0rd"
+            roles = [{ api = "resource", role = "admin" }]
+        "#).unwrap();
+
+        // dry run list all changes without creating anything
+        let plan = manifest.plan(&auth).await.unwrap();
+        assert_eq!(plan.to_string(), "+ api resource\n\
+            + procedure resource/read_model\n\
+            + procedure resource/create_model\n\
+            + role resource/admin\n\
+            + access resource/admin -> read_model\n\
+            + access resource/admin -> create_model\n\
+            + role profile resource/admin/age\n\
+            + user administrator\n\
+            + user role administrator -> resource/admin\n");
+        assert!(auth.read_api_by_name("resource").await.is_err());
+
+        // apply the manifest then the same manifest should give an empty plan
+        let applied = manifest.apply(&auth).await.unwrap();
+        assert_eq!(applied, plan);
+        let api = auth.read_api_by_name("resource").await.unwrap();
+        let role = auth.read_role_by_name(api.id, "admin").await.unwrap();
+        let user = auth.read_user_by_name("administrator").await.unwrap();
+        let profiles = auth.list_role_profile_by_role(role.id).await.unwrap();
+        assert_eq!(api.procedures.len(), 2);
+        assert_eq!(role.procedures.len(), 2);
+        assert!(user.roles.iter().any(|r| r.api_id == api.id && r.role == "admin"));
+        assert_eq!(profiles[0].value_type, U16T);
+        let plan = manifest.plan(&auth).await.unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.to_string(), "");
+
+        // changed manifest only plan the difference
+        manifest.apis[0].procedures.push(rmcs_api_client::provision::ProcedureManifest { name: "delete_model".to_owned(), description: std::string::String::new() });
+        manifest.apis[0].roles[0].procedures.push("delete_model".to_owned());
+        manifest.apis[0].roles[0].access_duration = 600;
+        let plan = manifest.apply(&auth).await.unwrap();
+        assert_eq!(plan.to_string(), "+ procedure resource/delete_model\n\
+            ~ role resource/admin\n\
+            + access resource/admin -> delete_model\n");
+        let role = auth.read_role(role.id).await.unwrap();
+        assert_eq!(role.procedures.len(), 3);
+        assert_eq!(role.access_duration, 600);
+        assert!(manifest.plan(&auth).await.unwrap().is_empty());
+
+        // procedure, role access and role removed from the manifest are only revoked with prune
+        manifest.apis[0].procedures.pop();
+        manifest.apis[0].roles[0].procedures.pop();
+        auth.create_role(Uuid::new_v4(), api.id, "viewer", true, false, 900, 43200).await.unwrap();
+        assert!(manifest.plan(&auth).await.unwrap().is_empty());
+        let prune = PlanOptions { prune: true };
+        let plan = manifest.plan_with(&auth, &prune).await.unwrap();
+        assert_eq!(plan.to_string(), "- access resource/admin -> delete_model\n\
+            - role resource/viewer\n\
+            - procedure resource/delete_model\n");
+        assert_eq!(manifest.apply_with(&auth, &prune).await.unwrap(), plan);
+        assert_eq!(auth.read_role(role.id).await.unwrap().procedures.len(), 2);
+        assert_eq!(auth.list_procedure_by_api(api.id).await.unwrap().len(), 2);
+        assert!(auth.read_role_by_name(api.id, "viewer").await.is_err());
+        assert!(manifest.plan_with(&auth, &prune).await.unwrap().is_empty());
+
+        // user role of an existing api which is not declared in the manifest is applied once
+        let mut manifest = Manifest::from_toml(r#"
+            [[users]]
+            name = "operator"
+            password = "0per4t0r_P4s5w0rd"
+            roles = [{ api = "resource", role = "admin" }]
+        "#).unwrap();
+        let plan = manifest.apply(&auth).await.unwrap();
+        assert_eq!(plan.to_string(), "+ user operator
+            + user role operator -> resource/admin
+");
+        assert!(manifest.plan(&auth).await.unwrap().is_empty());
+        assert!(manifest.apply(&auth).await.unwrap().is_empty());
+
+        // user role removed from the manifest is revoked with prune
+        manifest.users[0].roles.clear();
+        let plan = manifest.apply_with(&auth, &prune).await.unwrap();
+        assert_eq!(plan.to_string(), "- user role operator -> resource/admin\n");
+        assert!(auth.read_user_by_name("operator").await.unwrap().roles.is_empty());
+
+        auth_server.stop_server();
+    }
+
+    #[tokio::test]
+    async fn test_snapshot()
+    {
+        let api_id = Uuid::new_v4();
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            created: Utc::now(),
+            apis: vec![ApiSnapshot { id: api_id, name: "resource".to_owned(), access_key: Some(generate_access_key()), ..Default::default() }],
+            ..Default::default()
+        };
+
+        // snapshot written as json should be read back unchanged
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(Snapshot::from_json(&json).unwrap(), snapshot);
+
+        // import without provided password or with other snapshot version should error before calling the server
+        let channel = tonic::transport::Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        let auth = Auth::new_with_channel(channel);
+        let result = snapshot.import(&auth, &ImportOptions::new()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        let other = Snapshot { version: SNAPSHOT_VERSION + 1, ..snapshot.clone() };
+        let result = other.import(&auth, &ImportOptions::new().password(api_id, "Api_pa55w0rd")).await;
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_import()
+    {
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        // resource API with a read procedure for "admin" and "user" roles, admin role and "administrator" have profiles
+        let api_id = auth.create_api(Uuid::new_v4(), "resource", "localhost:9002", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let read_id = auth.create_procedure(Uuid::new_v4(), api_id, "ReadData", "").await.unwrap();
+        let admin_role_id = auth.create_role(Uuid::new_v4(), api_id, "admin", false, false, 900, 28800).await.unwrap();
+        auth.add_role_access(admin_role_id, read_id).await.unwrap();
+        let user_role_id = auth.create_role(Uuid::new_v4(), api_id, "user", true, false, 900, 28800).await.unwrap();
+        auth.add_role_access(user_role_id, read_id).await.unwrap();
+        let admin_id = auth.create_user(Uuid::new_v4(), "administrator", "admin@mail.co", "", ADMIN_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, admin_role_id).await.unwrap();
+        let user_id = auth.create_user(Uuid::new_v4(), "username", "user@mail.co", "", USER_PASSWORD).await.unwrap();
+        auth.add_user_role(user_id, user_role_id).await.unwrap();
+        auth.create_role_profile(admin_role_id, "age", U16T, SingleOptional).await.unwrap();
+        auth.create_user_profile(admin_id, "age", U16(30)).await.unwrap();
+
+        // list order of the server is not part of the snapshot content
+        let normalize = |mut snapshot: Snapshot| {
+            snapshot.created = DateTime::default();
+            snapshot.apis.sort_by_key(|a| a.id);
+            for api in &mut snapshot.apis {
+                api.procedures.sort_by_key(|p| p.id);
+                api.roles.sort_by_key(|r| r.id);
+                api.roles.iter_mut().for_each(|r| r.procedures.sort());
+            }
+            snapshot.users.sort_by_key(|u| u.id);
+            snapshot.users.iter_mut().for_each(|u| u.roles.sort());
+            snapshot
+        };
+
+        // export then import into empty tables should recreate the same state
+        let exported = Snapshot::export(&auth, &ExportOptions { secrets: true }).await.unwrap();
+        assert_eq!(exported.apis.len(), 1);
+        assert_eq!(exported.apis[0].procedures.len(), 1);
+        assert_eq!(exported.apis[0].roles.len(), 2);
+        assert_eq!(exported.users.len(), 2);
+        auth_server.truncate_tables().await.unwrap();
+        assert!(auth.read_api(api_id).await.is_err());
+        let options = ImportOptions::new()
+            .password(admin_id, ADMIN_PASSWORD)
+            .default_password(USER_PASSWORD);
+        let report = exported.import(&auth, &options).await.unwrap();
+        let imported = Snapshot::export(&auth, &ExportOptions { secrets: true }).await.unwrap();
+        assert_eq!(normalize(imported), normalize(exported.clone()));
+        assert!(report.access_keys.is_empty());
+
+        // imported users can login with the provided passwords
+        let login = auth.user_login("administrator", ADMIN_PASSWORD).await.unwrap();
+        assert_eq!(login.access_tokens.len(), 1);
+        auth.user_login("username", USER_PASSWORD).await.unwrap();
+
+        // skipped secrets are generated and returned when no password is provided
+        auth_server.truncate_tables().await.unwrap();
+        let report = exported.import(&auth, &ImportOptions::new().skip_secrets()).await.unwrap();
+        assert_eq!(report.passwords.len(), 3);
+        assert_ne!(report.access_keys[&api_id], exported.apis[0].access_key.clone().unwrap());
+        assert_eq!(auth.read_api(api_id).await.unwrap().access_key, report.access_keys[&api_id]);
+        auth.user_login("administrator", &report.passwords[&admin_id]).await.unwrap();
+
+        auth_server.stop_server();
+    }
+
+    #[test]
+    fn test_profile_validator()
+    {
+        let role_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let role_profile = |name: &str, value_type, mode| RoleProfileSchema { id: 0, role_id, name: name.to_owned(), value_type, mode };
+        let user_profile = |name: &str, value| UserProfileSchema { id: 0, user_id, name: name.to_owned(), value, order: 0 };
+        let role_profiles = vec![
+            role_profile("name", StringT, SingleRequired),
+            role_profile("age", U16T, SingleOptional),
+            role_profile("phone", StringT, MultipleOptional)
+        ];
+
+        // valid user profiles should not return any error
+        let user_profiles = vec![
+            user_profile("name", DataValue::String("Administrator".to_owned())),
+            user_profile("phone", DataValue::String("+6281234567890".to_owned())),
+            user_profile("phone", DataValue::String("+6281234567891".to_owned()))
+        ];
+        assert!(validate_profiles(&role_profiles, &user_profiles).is_empty());
+
+        // wrong type, unknown name, missing required and multiple single value should return field errors
+        let user_profiles = vec![
+            user_profile("age", U16(21)),
+            user_profile("age", U8(22)),
+            user_profile("email", DataValue::String("admin@mail.co".to_owned()))
+        ];
+        let errors = validate_profiles(&role_profiles, &user_profiles);
+        assert_eq!(errors.len(), 4);
+        assert!(errors.contains(&ProfileError::TypeUnmatch { name: "age".to_owned(), expected: U16T, found: U8T }));
+        assert!(errors.contains(&ProfileError::Unknown { name: "email".to_owned() }));
+        assert!(errors.contains(&ProfileError::Missing { name: "name".to_owned() }));
+        assert!(errors.contains(&ProfileError::Multiple { name: "age".to_owned() }));
+
+        // roles defining the same profile with different types should return conflict instead of type error
+        let mut role_profiles = role_profiles;
+        role_profiles.push(role_profile("age", U8T, SingleOptional));
+        let user_profiles = vec![
+            user_profile("name", DataValue::String("Administrator".to_owned())),
+            user_profile("age", U8(22))
+        ];
+        let errors = validate_profiles(&role_profiles, &user_profiles);
+        assert_eq!(errors, [ProfileError::Conflict { name: "age".to_owned(), types: vec![U16T, U8T] }]);
+    }
+
+    #[tokio::test]
+    async fn test_user_profile()
+    {
+        use rmcs_api_client as client;
+
+        // derived code refer to the client crate through its re-exported name
+        #[derive(Debug, PartialEq, UserProfile)]
+        #[profile(crate = "client")]
+        struct Profile {
+            #[profile(name = "fullname")]
+            name: std::string::String,
+            age: Option<u16>,
+            phone: Vec<std::string::String>
+        }
+
+        let _lock = AUTH_SERVER.lock().await;
+        let auth_server = TestServer::new(TestServerKind::Auth);
+        auth_server.truncate_tables().await.unwrap();
+        auth_server.start_server();
+        let auth = Auth::new(&auth_server.address).await;
+
+        // "administrator" has the admin role, the user role is added later
+        let api_id = auth.create_api(Uuid::new_v4(), "resource", "localhost:9002", "RESOURCE", "", "Ap1_P4s5w0rd", &generate_access_key()).await.unwrap();
+        let admin_role_id = auth.create_role(Uuid::new_v4(), api_id, "admin", false, false, 900, 28800).await.unwrap();
+        let user_role_id = auth.create_role(Uuid::new_v4(), api_id, "user", true, false, 900, 28800).await.unwrap();
+        let admin_id = auth.create_user(Uuid::new_v4(), "administrator", "admin@mail.co", "", ADMIN_PASSWORD).await.unwrap();
+        auth.add_user_role(admin_id, admin_role_id).await.unwrap();
+
+        // role profiles are created from struct fields
+        let ids = Profile::create_role_profiles(&auth, admin_role_id).await.unwrap();
+        let role_profiles = auth.list_role_profile_by_role(admin_role_id).await.unwrap();
+        assert_eq!(ids.len(), 3);
+        assert!(role_profiles.iter().any(|p| p.name == "fullname" && p.value_type == StringT && p.mode == SingleRequired));
+        assert!(role_profiles.iter().any(|p| p.name == "phone" && p.mode == MultipleOptional));
+
+        // saved struct is loaded back unchanged
+        let mut profile = Profile { name: "Administrator".to_owned(), age: Some(21), phone: vec!["+6281234567890".to_owned(), "+6281234567891".to_owned()] };
+        profile.save_to(&auth, admin_id).await.unwrap();
+        assert_eq!(auth.list_user_profile_by_user(admin_id).await.unwrap().len(), 4);
+        assert_eq!(Profile::load_from(&auth, admin_id).await.unwrap(), profile);
+
+        // saving changed struct update, create and delete the stored values
+        profile.name = "Admin".to_owned();
+        profile.age = None;
+        profile.phone = vec!["+6281234567899".to_owned()];
+        profile.save_to(&auth, admin_id).await.unwrap();
+        assert_eq!(auth.list_user_profile_by_user(admin_id).await.unwrap().len(), 2);
+        assert_eq!(Profile::load_from(&auth, admin_id).await.unwrap(), profile);
+
+        // checked profile is validated against the role profiles before it is created
+        let age_id = auth.create_user_profile_checked(admin_id, "age", U16(30)).await.unwrap();
+        let result = auth.create_user_profile_checked(admin_id, "email", DataValue::String("admin@mail.co".to_owned())).await;
+        assert!(matches!(result, Err(Error::Profile(errors)) if errors == [ProfileError::Unknown { name: "email".to_owned() }]));
+        let result = auth.create_user_profile_checked(admin_id, "age", U16(31)).await;
+        assert!(matches!(result, Err(Error::Profile(errors)) if errors == [ProfileError::Multiple { name: "age".to_owned() }]));
+
+        // update with wrong type is rejected before reaching the server
+        let result = auth.update_user_profile_checked(age_id, None, Some(U8(31))).await;
+        assert!(matches!(result, Err(Error::Profile(errors)) if errors == [ProfileError::TypeUnmatch { name: "age".to_owned(), expected: U16T, found: U8T }]));
+        assert_eq!(auth.read_user_profile(age_id).await.unwrap().value, U16(30));
+        auth.update_user_profile_checked(age_id, None, Some(U16(31))).await.unwrap();
+        profile.age = Some(31);
+        assert_eq!(Profile::load_from(&auth, admin_id).await.unwrap(), profile);
+
+        // other role of the user define age with different type
+        auth.create_role_profile(user_role_id, "age", StringT, SingleOptional).await.unwrap();
+        auth.add_user_role(admin_id, user_role_id).await.unwrap();
+        let result = auth.update_user_profile_checked(age_id, None, Some(U16(32))).await;
+        let errors = match result { Err(Error::Profile(errors)) => errors, _ => panic!("conflict should be reported") };
+        assert!(matches!(&errors[..], [ProfileError::Conflict { name, types }] if name == "age" && types.contains(&U16T) && types.contains(&StringT)));
+        let profiles = auth.list_user_profile_by_user(admin_id).await.unwrap();
+        assert_eq!(profiles.len(), 3);
+
+        auth_server.stop_server();
+    }
+
+    #[test]
+    fn test_user_profile_derive()
+    {
+        #[derive(Debug, PartialEq, UserProfile)]
+        struct Profile {
+            #[profile(name = "fullname")]
+            name: std::string::String,
+            age: Option<u16>,
+            phone: Vec<std::string::String>
+        }
+
+        // struct fields should map to profile names, data types and modes
+        let fields = Profile::profile_fields();
+        assert_eq!(fields.iter().map(|f| f.name).collect::<Vec<_>>(), ["fullname", "age", "phone"]);
+        assert_eq!(fields[0].value_type, StringT);
+        assert_eq!(fields[0].mode, SingleRequired);
+        assert_eq!(fields[1].value_type, U16T);
+        assert_eq!(fields[1].mode, SingleOptional);
+        assert_eq!(fields[2].mode, MultipleOptional);
+
+        // convert struct to profile values and back
+        let profile = Profile { name: "Administrator".to_owned(), age: Some(21), phone: vec!["+6281234567890".to_owned(), "+6281234567891".to_owned()] };
+        let user_id = Uuid::new_v4();
+        let user_profiles: Vec<UserProfileSchema> = profile.to_profiles().into_iter().enumerate()
+            .map(|(i, (name, value))| UserProfileSchema { id: i as i32, user_id, name: name.to_owned(), value, order: i as i16 })
+            .collect();
+        assert_eq!(user_profiles.len(), 4);
+        assert_eq!(Profile::from_profiles(&user_profiles).unwrap(), profile);
+
+        // missing required value and wrong type should return profile errors
+        let user_profiles = vec![UserProfileSchema { id: 0, user_id, name: "age".to_owned(), value: U8(21), order: 0 }];
+        let result = Profile::from_profiles(&user_profiles);
+        assert!(matches!(result, Err(Error::Profile(errors)) if errors.len() == 2));
+
+        // field with the same name as locals of the generated code
+        #[derive(Debug, PartialEq, UserProfile)]
+        struct Locals {
+            errors: u16,
+            profiles: Option<u16>,
+            values: Vec<u16>
+        }
+        let locals = Locals { errors: 1, profiles: Some(2), values: vec![3, 4] };
+        let user_profiles: Vec<UserProfileSchema> = locals.to_profiles().into_iter().enumerate()
+            .map(|(i, (name, value))| UserProfileSchema { id: i as i32, user_id, name: name.to_owned(), value, order: i as i16 })
+            .collect();
+        assert_eq!(Locals::from_profiles(&user_profiles).unwrap(), locals);
+    }
+
+    // encoded file descriptor set of a package with services and their method names
+    fn service_descriptor(package: &str, services: &[(&str, &[&str])]) -> Vec<u8> {
+        use prost::Message;
+        use prost_types::{FileDescriptorSet, FileDescriptorProto, ServiceDescriptorProto, MethodDescriptorProto};
+
+        let service = |name: &str, methods: &[&str]| ServiceDescriptorProto {
+            name: Some(name.to_owned()),
+            method: methods.iter().map(|m| MethodDescriptorProto { name: Some(m.to_string()), ..Default::default() }).collect(),
+            ..Default::default()
+        };
+        let descriptor_set = FileDescriptorSet { file: vec![FileDescriptorProto {
+            package: Some(package.to_owned()),
+            service: services.iter().map(|(name, methods)| service(name, methods)).collect(),
+            ..Default::default()
+        }]};
+        descriptor_set.encode_to_vec()
+    }
+
+    #[test]
+    fn test_descriptor_methods()
+    {
+        let descriptor = service_descriptor("model", &[
+            ("ModelService", &["ReadModel", "CreateModel"]),
+            ("ConfigService", &["ReadModelConfig"]),
+            ("ProxyService", &["ReadHTTP", "HTTPRequest", "ReadHTTPData"])
+        ]);
+
+        // all methods or methods of selected service only
+        let methods = descriptor_methods(&descriptor, &SyncOptions::default()).unwrap();
+        assert_eq!(methods, ["ReadModel", "CreateModel", "ReadModelConfig", "ReadHTTP", "HTTPRequest", "ReadHTTPData"]);
+        let options = SyncOptions { services: vec!["model.ModelService".to_owned()], snake_case: true, ..Default::default() };
+        let methods = descriptor_methods(&descriptor, &options).unwrap();
+        assert_eq!(methods, ["read_model", "create_model"]);
+        assert!(matches!(descriptor_methods(&[0xff, 0xff], &options), Err(Error::Parse(_))));
+
+        // run of capital letters is converted as one word
+        let options = SyncOptions { services: vec!["ProxyService".to_owned()], snake_case: true, ..Default::default() };
+        let methods = descriptor_methods(&descriptor, &options).unwrap();
+        assert_eq!(methods, ["read_http", "http_request", "read_http_data"]);
+
+        // unknown service and descriptor without method are rejected
+        let options = SyncOptions { services: vec!["ModelService".to_owned(), "DataService".to_owned()], ..Default::default() };
+        assert!(matches!(descriptor_methods(&descriptor, &options), Err(Error::InvalidArgument(message)) if message.contains("DataService")));
+        let empty = service_descriptor("model", &[("ModelService", &[])]);
+        assert!(matches!(descriptor_methods(&empty, &SyncOptions::default()), Err(Error::InvalidArgument(_))));
+        assert!(matches!(descriptor_methods(&[], &SyncOptions::default()), Err(Error::InvalidArgument(_))));
+    }
+
 }